@@ -0,0 +1,114 @@
+//! Constructs a tensor of a fuzzer-chosen element type & shape from arbitrary bytes, then extracts it back through
+//! every extraction path, checking that the data round-trips. The only errors we allow are the typed errors returned
+//! for invalid shapes & mismatched element types; any panic or sanitizer report is a bug.
+//!
+//! Run with `cargo +nightly fuzz run extract` from the repository root. `cargo fuzz` builds with AddressSanitizer by
+//! default, which catches out-of-bounds reads in `extract_primitive_array` and the string offset logic.
+
+#![no_main]
+
+use std::fmt::Debug;
+
+use libfuzzer_sys::fuzz_target;
+use ort::{PrimitiveTensorElementType, Tensor, TensorData, TensorElementType};
+
+fuzz_target!(|data: &[u8]| {
+	let Some((&selector, data)) = data.split_first() else {
+		return;
+	};
+	let Some((&rank, data)) = data.split_first() else {
+		return;
+	};
+	let rank = usize::from(rank % 4);
+	if data.len() < rank {
+		return;
+	}
+
+	let (dims, data) = data.split_at(rank);
+	// zero-sized dimensions are rejected when creating a tensor, which exercises the shape validation error path
+	let shape: Vec<i64> = dims.iter().map(|&d| i64::from(d % 5)).collect();
+	let len = shape.iter().product::<i64>() as usize;
+
+	macro_rules! check {
+		($t:ty, $n:literal, $conv:expr) => {{
+			let elements: Vec<$t> = data.chunks_exact($n).map(|c| $conv(c.try_into().unwrap())).collect();
+			check_primitive(&shape, len, elements)
+		}};
+	}
+
+	match selector % 14 {
+		0 => check!(f32, 4, f32::from_ne_bytes),
+		1 => check!(f64, 8, f64::from_ne_bytes),
+		2 => check!(half::f16, 2, |c| half::f16::from_bits(u16::from_ne_bytes(c))),
+		3 => check!(half::bf16, 2, |c| half::bf16::from_bits(u16::from_ne_bytes(c))),
+		4 => check!(i8, 1, i8::from_ne_bytes),
+		5 => check!(i16, 2, i16::from_ne_bytes),
+		6 => check!(i32, 4, i32::from_ne_bytes),
+		7 => check!(i64, 8, i64::from_ne_bytes),
+		8 => check!(u8, 1, u8::from_ne_bytes),
+		9 => check!(u16, 2, u16::from_ne_bytes),
+		10 => check!(u32, 4, u32::from_ne_bytes),
+		11 => check!(u64, 8, u64::from_ne_bytes),
+		12 => check!(bool, 1, |c: [u8; 1]| c[0] & 1 != 0),
+		_ => check_strings(&shape, len, data)
+	}
+});
+
+fn check_primitive<T: PrimitiveTensorElementType + Clone + Debug + 'static>(shape: &[i64], len: usize, mut elements: Vec<T>) {
+	// only truncate if there's enough data, so that length mismatches are exercised too
+	if elements.len() > len {
+		elements.truncate(len);
+	}
+	// compare bytes rather than elements so that NaNs compare equal
+	let expected_bytes = unsafe { std::slice::from_raw_parts(elements.as_ptr().cast::<u8>(), std::mem::size_of_val(&elements[..])) }.to_vec();
+
+	let Ok(tensor) = Tensor::from_array((shape.to_vec(), elements)) else {
+		return;
+	};
+
+	let (extracted_shape, extracted) = tensor.extract_raw_tensor();
+	assert_eq!(extracted_shape, shape);
+	assert_eq!(extracted.len(), len);
+
+	let array = tensor.extract_tensor();
+	assert_eq!(array.shape().iter().map(|&d| d as i64).collect::<Vec<_>>(), shape);
+
+	let value = tensor.upcast();
+	match value.try_extract_dynamic().unwrap() {
+		TensorData::PrimitiveView { ty, shape: extracted_shape, data } => {
+			assert_eq!(ty, T::into_tensor_element_type());
+			assert_eq!(extracted_shape, shape);
+			assert_eq!(data, &expected_bytes[..]);
+		}
+		TensorData::Strings { .. } => panic!("primitive tensor extracted as strings")
+	}
+
+	// extracting with the wrong element type must fail with an error rather than reinterpreting the data
+	if T::into_tensor_element_type() != TensorElementType::Uint8 {
+		assert!(value.try_extract_raw_tensor::<u8>().is_err());
+	} else {
+		assert!(value.try_extract_raw_tensor::<i8>().is_err());
+	}
+	assert!(value.try_extract_raw_string_tensor().is_err());
+
+	// casting may fail for some element types, but must never panic
+	let _ = value.try_extract_as::<f32>();
+}
+
+fn check_strings(shape: &[i64], len: usize, data: &[u8]) {
+	let mut strings: Vec<String> = data.split(|&b| b == 0).map(|s| String::from_utf8_lossy(s).into_owned()).collect();
+	if strings.len() > len {
+		strings.truncate(len);
+	}
+
+	let Ok(tensor) = Tensor::from_string_array((shape.to_vec(), strings.clone())) else {
+		return;
+	};
+
+	let (extracted_shape, extracted) = tensor.try_extract_raw_string_tensor().unwrap();
+	assert_eq!(extracted_shape, shape);
+	assert_eq!(extracted, strings);
+
+	assert_eq!(tensor.try_extract_dynamic().unwrap(), TensorData::Strings { shape: shape.to_vec(), data: strings });
+	assert!(tensor.try_extract_raw_tensor::<u8>().is_err());
+}
@@ -98,7 +98,13 @@ pub enum ErrorCode {
 	ModelLoaded,
 	NotImplemented,
 	InvalidGraph,
-	ExecutionProviderFailure
+	ExecutionProviderFailure,
+	/// A tensor element type is not supported by this build of `ort`, either because it requires a crate feature which
+	/// is not enabled, or because `ort` does not support it at all.
+	///
+	/// This is never returned by ONNX Runtime itself; it is converted to [`ErrorCode::NotImplemented`] when passed back
+	/// to ONNX Runtime.
+	UnsupportedTensorElementType
 }
 
 impl From<ort_sys::OrtErrorCode> for ErrorCode {
@@ -136,7 +142,8 @@ impl From<ErrorCode> for ort_sys::OrtErrorCode {
 			ErrorCode::ModelLoaded => ort_sys::OrtErrorCode::ORT_MODEL_LOADED,
 			ErrorCode::NotImplemented => ort_sys::OrtErrorCode::ORT_NOT_IMPLEMENTED,
 			ErrorCode::InvalidGraph => ort_sys::OrtErrorCode::ORT_INVALID_GRAPH,
-			ErrorCode::ExecutionProviderFailure => ort_sys::OrtErrorCode::ORT_EP_FAIL
+			ErrorCode::ExecutionProviderFailure => ort_sys::OrtErrorCode::ORT_EP_FAIL,
+			ErrorCode::UnsupportedTensorElementType => ort_sys::OrtErrorCode::ORT_NOT_IMPLEMENTED
 		}
 	}
 }
@@ -47,6 +47,9 @@ pub use self::tensor::ArrayExtensions;
 #[cfg(feature = "training")]
 #[cfg_attr(docsrs, doc(cfg(feature = "training")))]
 pub use self::training::*;
+#[cfg(feature = "dlpack")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dlpack")))]
+pub use self::value::dlpack;
 pub use self::{
 	environment::{Environment, EnvironmentBuilder, EnvironmentGlobalThreadPoolOptions, get_environment, init},
 	error::{Error, ErrorCode, Result},
@@ -56,19 +59,20 @@ pub use self::{
 	metadata::ModelMetadata,
 	operator::{
 		InferShapeFn, Operator, OperatorDomain,
-		io::{OperatorInput, OperatorOutput},
-		kernel::{Kernel, KernelAttributes, KernelContext}
+		io::{InputOutputCharacteristic, OperatorInput, OperatorOutput},
+		kernel::{Kernel, KernelAttributes, KernelContext, KernelOutput, LogLevel, Logger}
 	},
 	session::{
 		GraphOptimizationLevel, HasSelectedOutputs, InMemorySession, InferenceFut, Input, NoSelectedOutputs, Output, OutputSelector, OverridableInitializer,
 		RunOptions, SelectedOutputMarker, Session, SessionBuilder, SessionInputValue, SessionInputs, SessionOutputs, SharedSessionInner
 	},
-	tensor::{IntoTensorElementType, PrimitiveTensorElementType, TensorElementType, Utf8Data},
+	tensor::{IntoTensorElementType, NumericTensorElementType, PrimitiveTensorElementType, TensorElementType, Utf8Data, supported_element_types},
 	value::{
-		DowncastableTarget, DynMap, DynMapRef, DynMapRefMut, DynMapValueType, DynSequence, DynSequenceRef, DynSequenceRefMut, DynSequenceValueType, DynTensor,
-		DynTensorRef, DynTensorRefMut, DynTensorValueType, DynValue, DynValueTypeMarker, Map, MapRef, MapRefMut, MapValueType, MapValueTypeMarker, Sequence,
-		SequenceRef, SequenceRefMut, SequenceValueType, SequenceValueTypeMarker, Tensor, TensorRef, TensorRefMut, TensorValueType, TensorValueTypeMarker,
-		Value, ValueRef, ValueRefMut, ValueType, ValueTypeMarker
+		DiffSummary, DowncastableTarget, DynMap, DynMapRef, DynMapRefMut, DynMapValueType, DynSequence, DynSequenceRef, DynSequenceRefMut,
+		DynSequenceValueType, DynTensor, DynTensorRef, DynTensorRefMut, DynTensorValueType, DynValue, DynValueTypeMarker, ExtractInto, FromValue, Map, MapRef,
+		MapRefMut, MapValueType, MapValueTypeMarker, Rounding, Sequence, SequenceRef, SequenceRefMut, SequenceValueType, SequenceValueTypeMarker, Tensor,
+		TensorData, TensorDescriptor, TensorRef, TensorRefMut, TensorTypeInfo, TensorValueType, TensorValueTypeMarker, TensorVisitor, ToValue, Value, ValueRef,
+		ValueRefMut, ValueType, ValueTypeMarker, zip_string_rows, zip_string_scores
 	}
 };
 
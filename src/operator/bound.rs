@@ -1,6 +1,8 @@
 use std::{
+	any::Any,
 	ffi::CString,
 	marker::PhantomData,
+	panic::{self, AssertUnwindSafe},
 	ptr::{self, NonNull}
 };
 
@@ -9,7 +11,10 @@ use super::{
 	io::InputOutputCharacteristic,
 	kernel::{Kernel, KernelAttributes, KernelContext}
 };
-use crate::{error::IntoStatus, extern_system_fn};
+use crate::{
+	error::{Error, IntoStatus, Result},
+	extern_system_fn
+};
 
 #[repr(C)] // <- important! a defined layout allows us to store extra data after the `OrtCustomOp` that we can retrieve later
 pub(crate) struct BoundOperator<O: Operator> {
@@ -72,7 +77,7 @@ impl<O: Operator> BoundOperator<O> {
 			info: *const ort_sys::OrtKernelInfo,
 			kernel_ptr: *mut *mut ort_sys::c_void
 		) -> *mut ort_sys::OrtStatus {
-			let kernel = match O::create_kernel(&KernelAttributes::new(info)) {
+			let kernel = match catch_panic(|| O::create_kernel(&KernelAttributes::new(info))) {
 				Ok(kernel) => kernel,
 				e => return e.into_status()
 			};
@@ -84,7 +89,21 @@ impl<O: Operator> BoundOperator<O> {
 	extern_system_fn! {
 		pub(crate) unsafe fn ComputeKernelV2(kernel_ptr: *mut ort_sys::c_void, context: *mut ort_sys::OrtKernelContext) -> *mut ort_sys::OrtStatus {
 			let context = KernelContext::new(context);
-			O::Kernel::compute(unsafe { &mut *kernel_ptr.cast::<O::Kernel>() }, &context).into_status()
+			#[cfg(feature = "tracing")]
+			let span = tracing::span!(
+				tracing::Level::DEBUG,
+				"compute",
+				operator = O::name(),
+				inputs = context.num_inputs().unwrap_or_default(),
+				error = tracing::field::Empty
+			)
+			.entered();
+			let result = catch_panic(|| O::Kernel::compute(unsafe { &mut *kernel_ptr.cast::<O::Kernel>() }, &context));
+			#[cfg(feature = "tracing")]
+			if let Err(e) = &result {
+				span.record("error", tracing::field::display(e));
+			}
+			result.into_status()
 		}
 	}
 
@@ -204,11 +223,29 @@ impl<O: Operator> BoundOperator<O> {
 
 	extern_system_fn! {
 		pub(crate) unsafe fn InferOutputShapeFn(_: *const ort_sys::OrtCustomOp, arg1: *mut ort_sys::OrtShapeInferContext) -> *mut ort_sys::OrtStatus {
-			O::get_infer_shape_function().expect("missing infer shape function")(arg1).into_status()
+			catch_panic(|| O::get_infer_shape_function().expect("missing infer shape function")(arg1)).into_status()
 		}
 	}
 }
 
+/// Calls `f`, converting any panic into an [`Error`].
+///
+/// Unwinding from a Rust panic across the FFI boundary into ONNX Runtime is undefined behavior, so all user code
+/// called from within an `extern_system_fn!` must be wrapped with this.
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+	panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| Err(Error::new(format!("operator panicked: {}", panic_message(&*payload)))))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+	if let Some(msg) = payload.downcast_ref::<&'static str>() {
+		msg
+	} else if let Some(msg) = payload.downcast_ref::<String>() {
+		msg.as_str()
+	} else {
+		"<non-string panic payload>"
+	}
+}
+
 pub(crate) struct ErasedBoundOperator(NonNull<()>);
 
 unsafe impl Send for ErasedBoundOperator {}
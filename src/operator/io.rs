@@ -1,11 +1,16 @@
 use crate::{memory::MemoryType, tensor::TensorElementType};
 
+/// Describes how many values an operator's input or output accepts.
 #[repr(i32)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum InputOutputCharacteristic {
+	/// Exactly one value must be provided.
 	#[default]
 	Required = 0,
+	/// The value may be omitted, in which case [`crate::KernelContext::input`] returns `None` for it.
 	Optional = 1,
+	/// Any number of values may be provided, e.g. for concat-style operators. Only the last input or output of an
+	/// operator may be variadic.
 	Variadic = 2
 }
 
@@ -28,6 +33,27 @@ pub struct OperatorInput {
 }
 
 impl OperatorInput {
+	/// Creates an input of element type `r#type` with the given characteristic.
+	///
+	/// [`InputOutputCharacteristic::Variadic`] inputs created this way are homogenous (i.e. every value must be of
+	/// type `r#type`) and accept a minimum of 1 value; use [`OperatorInput::variadic`] for other configurations.
+	///
+	/// ```
+	/// # use ort::{InputOutputCharacteristic, OperatorInput, TensorElementType};
+	/// let inputs = [
+	/// 	OperatorInput::new(TensorElementType::Float32, InputOutputCharacteristic::Required),
+	/// 	OperatorInput::new(TensorElementType::Int64, InputOutputCharacteristic::Optional)
+	/// ];
+	/// ```
+	#[inline]
+	pub const fn new(r#type: TensorElementType, characteristic: InputOutputCharacteristic) -> Self {
+		match characteristic {
+			InputOutputCharacteristic::Required => Self::required(r#type),
+			InputOutputCharacteristic::Optional => Self::optional(r#type),
+			InputOutputCharacteristic::Variadic => Self::variadic(1).homogenous(r#type)
+		}
+	}
+
 	#[inline]
 	pub const fn required(r#type: TensorElementType) -> Self {
 		Self {
@@ -83,6 +109,19 @@ pub struct OperatorOutput {
 }
 
 impl OperatorOutput {
+	/// Creates an output of element type `r#type` with the given characteristic.
+	///
+	/// [`InputOutputCharacteristic::Variadic`] outputs created this way are homogenous (i.e. every value must be of
+	/// type `r#type`) and produce a minimum of 1 value; use [`OperatorOutput::variadic`] for other configurations.
+	#[inline]
+	pub const fn new(r#type: TensorElementType, characteristic: InputOutputCharacteristic) -> Self {
+		match characteristic {
+			InputOutputCharacteristic::Required => Self::required(r#type),
+			InputOutputCharacteristic::Optional => Self::optional(r#type),
+			InputOutputCharacteristic::Variadic => Self::variadic(1).homogenous(r#type)
+		}
+	}
+
 	#[inline]
 	pub const fn required(r#type: TensorElementType) -> Self {
 		Self {
@@ -1,15 +1,19 @@
 use std::{
+	cell::RefCell,
 	ffi::{CString, c_char, c_void},
+	marker::PhantomData,
 	ops::{Deref, DerefMut},
+	path::Path,
 	ptr::{self, NonNull}
 };
 
 use crate::{
-	error::{Error, Result, status_to_result},
-	memory::{Allocator, MemoryInfo},
+	error::{Error, ErrorCode, Result, status_to_result},
+	memory::{Allocator, MemoryInfo, MemoryType},
 	ortsys,
 	session::{Input, Output},
-	value::{DowncastableTarget, DynValue, Value, ValueRef, ValueRefMut, ValueType}
+	tensor::{PrimitiveTensorElementType, TensorElementType},
+	value::{DowncastableTarget, DynTensor, DynValue, Value, ValueRef, ValueRefMut, ValueType}
 };
 
 pub trait Kernel {
@@ -24,6 +28,12 @@ impl Kernel for DummyKernel {
 	}
 }
 
+/// Information about a custom operator node, passed to [`Operator::create_kernel`](crate::Operator::create_kernel).
+///
+/// ONNX Runtime does not expose session configuration entries to custom operator kernels. Per-node configuration
+/// should instead be provided through node attributes (see [`KernelAttributes::get`]); configuration that applies to
+/// all nodes of an operator must be provided out-of-band, e.g. through a `static` read by the operator's
+/// `create_kernel` implementation.
 pub struct KernelAttributes(NonNull<ort_sys::OrtKernelInfo>);
 
 impl KernelAttributes {
@@ -31,12 +41,26 @@ impl KernelAttributes {
 		Self(NonNull::from(unsafe { &*info }))
 	}
 
+	/// Returns the value of the node attribute `name`, or `None` if the node has no such attribute or the attribute is
+	/// not of type `T`.
+	///
+	/// Supported attribute types are `f32`, `i64`, `String`, `Vec<f32>`, `Vec<i64>`, and tensors (as [`Value`]s or
+	/// [`ValueRef`]s; see also [`KernelAttributes::get_tensor`]).
 	#[allow(private_bounds)]
 	pub fn get<'s, T: GetKernelAttribute<'s>>(&'s self, name: impl AsRef<str>) -> Option<T> {
 		let name = CString::new(name.as_ref()).ok()?;
 		T::get_from(self.0.as_ptr(), name.as_ptr())
 	}
 
+	/// Returns the value of the tensor attribute `name`, e.g. a constant lookup table baked into the node, or `None` if
+	/// the node has no such attribute or the attribute is not a tensor.
+	///
+	/// The returned tensor is a copy of the attribute's data allocated in CPU memory, and is owned by the caller, so it
+	/// may be stored in the kernel and outlive these attributes.
+	pub fn get_tensor(&self, name: impl AsRef<str>) -> Option<DynTensor> {
+		self.get::<DynTensor>(name)
+	}
+
 	pub fn inputs(&self) -> Result<Vec<Input>> {
 		let mut num_inputs: ort_sys::size_t = 0;
 		ortsys![unsafe KernelInfo_GetInputCount(self.0.as_ptr(), &mut num_inputs)?];
@@ -53,7 +77,7 @@ impl KernelAttributes {
 				.map_err(Error::wrap)?;
 			let mut type_info = ptr::null_mut();
 			ortsys![unsafe KernelInfo_GetInputTypeInfo(self.0.as_ptr(), idx as _, &mut type_info)?; nonNull(type_info)];
-			let input_type = ValueType::from_type_info(type_info);
+			let input_type = ValueType::from_type_info(type_info)?;
 			inputs.push(Input { name, input_type })
 		}
 		Ok(inputs)
@@ -75,12 +99,80 @@ impl KernelAttributes {
 				.map_err(Error::wrap)?;
 			let mut type_info = ptr::null_mut();
 			ortsys![unsafe KernelInfo_GetOutputTypeInfo(self.0.as_ptr(), idx as _, &mut type_info)?; nonNull(type_info)];
-			let output_type = ValueType::from_type_info(type_info);
+			let output_type = ValueType::from_type_info(type_info)?;
 			outputs.push(Output { name, output_type })
 		}
 		Ok(outputs)
 	}
 
+	/// Returns the value of input `idx` if it is a constant provided by the graph (i.e. an initializer), or `None` if
+	/// the input is only known at runtime.
+	///
+	/// Constant inputs can be used in [`Operator::create_kernel`](crate::Operator::create_kernel) to precompute state
+	/// shared by every invocation of the kernel. The value is owned by the session and lives at least as long as the
+	/// kernel; copy its data if it is needed beyond that.
+	///
+	/// # Errors
+	/// May return an error if `idx` is out of bounds.
+	pub fn constant_input(&self, idx: usize) -> Result<Option<ValueRef<'_>>> {
+		let mut is_constant: ort_sys::c_int = 0;
+		let mut value_ptr: *const ort_sys::OrtValue = ptr::null();
+		ortsys![unsafe KernelInfoGetConstantInput_tensor(self.0.as_ptr(), idx as _, &mut is_constant, &mut value_ptr)?];
+		if is_constant == 0 {
+			return Ok(None);
+		}
+		Ok(NonNull::new(value_ptr.cast_mut()).map(|c| ValueRef::new(unsafe { Value::from_ptr_nodrop(c, None) })))
+	}
+
+	/// Returns the number of this node's inputs which are constants provided by the graph; see
+	/// [`KernelAttributes::constant_input`].
+	///
+	/// Constant inputs are not necessarily the first inputs of the node, so to find them, iterate over all of the
+	/// node's inputs with [`KernelAttributes::constant_input`]:
+	/// ```ignore
+	/// fn create_kernel(attributes: &KernelAttributes) -> ort::Result<Self::Kernel> {
+	/// 	let mut constants = Vec::with_capacity(attributes.constant_input_count()?);
+	/// 	for idx in 0..attributes.inputs()?.len() {
+	/// 		if let Some(value) = attributes.constant_input(idx)? {
+	/// 			constants.push((idx, value.try_extract_raw_tensor::<f32>()?.1.to_vec()));
+	/// 		}
+	/// 	}
+	/// 	Ok(MyKernel { constants })
+	/// }
+	/// ```
+	///
+	/// ONNX Runtime does not report this count directly, so this queries each input of the node in turn.
+	pub fn constant_input_count(&self) -> Result<usize> {
+		let mut num_inputs: ort_sys::size_t = 0;
+		ortsys![unsafe KernelInfo_GetInputCount(self.0.as_ptr(), &mut num_inputs)?];
+
+		let mut count = 0;
+		for idx in 0..num_inputs as usize {
+			if self.constant_input(idx)?.is_some() {
+				count += 1;
+			}
+		}
+		Ok(count)
+	}
+
+	/// Returns the element type & shape ONNX Runtime expects output `idx` of this node to have, as determined by shape
+	/// inference when the session was created. Dimensions which could not be inferred are `-1`.
+	///
+	/// ONNX Runtime does not expose this information to [`KernelContext`], so kernels which want to validate their
+	/// outputs against it should query it in [`Operator::create_kernel`](crate::Operator::create_kernel) and store it
+	/// in the kernel.
+	///
+	/// # Errors
+	/// May return an error if `idx` is out of bounds, or the output is not a tensor.
+	pub fn output_type_shape(&self, idx: usize) -> Result<(TensorElementType, Vec<i64>)> {
+		let mut type_info = ptr::null_mut();
+		ortsys![unsafe KernelInfo_GetOutputTypeInfo(self.0.as_ptr(), idx as _, &mut type_info)?; nonNull(type_info)];
+		match ValueType::from_type_info(type_info)? {
+			ValueType::Tensor { ty, dimensions } => Ok((ty, dimensions)),
+			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Output {idx} is of type {t}; expected a tensor")))
+		}
+	}
+
 	pub fn node_name(&self) -> Result<String> {
 		let mut name_len: ort_sys::size_t = 0;
 		ortsys![unsafe KernelInfo_GetNodeName(self.0.as_ptr(), ptr::null_mut(), &mut name_len)?];
@@ -88,6 +180,45 @@ impl KernelAttributes {
 		ortsys![unsafe KernelInfo_GetNodeName(self.0.as_ptr(), name.as_mut_ptr().cast::<c_char>(), &mut name_len)?];
 		CString::from_vec_with_nul(name).map_err(Error::wrap)?.into_string().map_err(Error::wrap)
 	}
+
+	/// Returns the allocator of the given [`MemoryType`] for the execution provider this node was assigned to.
+	pub fn allocator(&self, memory_type: MemoryType) -> Result<Allocator> {
+		let mut allocator_ptr = ptr::null_mut();
+		ortsys![unsafe KernelInfoGetAllocator(self.0.as_ptr(), memory_type.into(), &mut allocator_ptr)?; nonNull(allocator_ptr)];
+		Ok(unsafe { Allocator::from_raw_unchecked(allocator_ptr) })
+	}
+
+	/// Returns the [`MemoryInfo`] of the default allocator for the execution provider this node was assigned to, i.e.
+	/// the device the kernel's inputs & outputs reside on.
+	///
+	/// ONNX Runtime does not expose this information to [`KernelContext`], so kernels which need to allocate scratch
+	/// memory on the correct device should store the memory info in
+	/// [`Operator::create_kernel`](crate::Operator::create_kernel) and pass it to [`KernelContext::allocator`]:
+	/// ```ignore
+	/// struct MyKernel {
+	/// 	memory_info: MemoryInfo
+	/// }
+	///
+	/// impl Operator for MyOperator {
+	/// 	// ...
+	/// 	fn create_kernel(attributes: &KernelAttributes) -> ort::Result<Self::Kernel> {
+	/// 		Ok(MyKernel { memory_info: attributes.memory_info()? })
+	/// 	}
+	/// }
+	///
+	/// impl Kernel for MyKernel {
+	/// 	fn compute(&mut self, ctx: &KernelContext) -> ort::Result<()> {
+	/// 		let allocator = ctx.allocator(&self.memory_info)?;
+	/// 		// ...
+	/// 	}
+	/// }
+	/// ```
+	pub fn memory_info(&self) -> Result<MemoryInfo> {
+		let allocator = self.allocator(MemoryType::Default)?;
+		// the allocator's memory info is only valid for as long as the allocator is, so make an owned copy
+		let info = allocator.memory_info();
+		MemoryInfo::new(info.allocation_device(), info.device_id(), info.allocator_type(), info.memory_type())
+	}
 }
 
 pub trait GetKernelAttribute<'s> {
@@ -138,6 +269,9 @@ impl<'s> GetKernelAttribute<'s> for Vec<f32> {
 	{
 		let mut size = ort_sys::size_t::default();
 		status_to_result(ortsys![unsafe KernelInfoGetAttributeArray_float(info, name, ptr::null_mut(), &mut size)]).ok()?;
+		if size == 0 {
+			return Some(Vec::new());
+		}
 		let mut out = vec![0f32; size as _];
 		status_to_result(ortsys![unsafe KernelInfoGetAttributeArray_float(info, name, out.as_mut_ptr(), &mut size)]).ok()?;
 		Some(out)
@@ -151,6 +285,9 @@ impl<'s> GetKernelAttribute<'s> for Vec<i64> {
 	{
 		let mut size = ort_sys::size_t::default();
 		status_to_result(ortsys![unsafe KernelInfoGetAttributeArray_int64(info, name, ptr::null_mut(), &mut size)]).ok()?;
+		if size == 0 {
+			return Some(Vec::new());
+		}
 		let mut out = vec![0i64; size as _];
 		status_to_result(ortsys![unsafe KernelInfoGetAttributeArray_int64(info, name, out.as_mut_ptr(), &mut size)]).ok()?;
 		Some(out)
@@ -174,6 +311,20 @@ impl<'s, T: DowncastableTarget> GetKernelAttribute<'s> for ValueRef<'s, T> {
 	}
 }
 
+impl<'s, T: DowncastableTarget> GetKernelAttribute<'s> for Value<T> {
+	fn get_from(info: *mut ort_sys::OrtKernelInfo, name: *const ort_sys::c_char) -> Option<Self>
+	where
+		Self: Sized
+	{
+		// the allocator is only used to allocate the tensor, which is then owned (and released) by the returned `Value`
+		let allocator = Allocator::default();
+
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+		status_to_result(ortsys![unsafe KernelInfoGetAttribute_tensor(info, name, allocator.ptr.as_ptr(), &mut value_ptr)]).ok()?;
+		unsafe { DynValue::from_ptr(NonNull::new(value_ptr)?, None) }.downcast().ok()
+	}
+}
+
 pub struct ScratchBuffer<T> {
 	allocator: Allocator,
 	buffer: *mut T,
@@ -201,14 +352,48 @@ impl<T> Drop for ScratchBuffer<T> {
 	}
 }
 
+/// A mutable view of a kernel output's data, returned by [`KernelContext::output_like_mut`].
+///
+/// The view dereferences to a slice of the output's elements. Only one view of each output can exist at a time; the
+/// output is released for further borrows when the view is dropped.
+pub struct KernelOutput<'k, T> {
+	ctx: &'k KernelContext,
+	idx: usize,
+	data: *mut T,
+	len: usize,
+	_value: ValueRefMut<'k>
+}
+
+impl<T> Deref for KernelOutput<'_, T> {
+	type Target = [T];
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { std::slice::from_raw_parts(self.data.cast_const(), self.len) }
+	}
+}
+impl<T> DerefMut for KernelOutput<'_, T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		unsafe { std::slice::from_raw_parts_mut(self.data, self.len) }
+	}
+}
+
+impl<T> Drop for KernelOutput<'_, T> {
+	fn drop(&mut self) {
+		self.ctx.borrowed_outputs.borrow_mut().retain(|&idx| idx != self.idx);
+	}
+}
+
 pub struct KernelContext {
-	ptr: NonNull<ort_sys::OrtKernelContext>
+	ptr: NonNull<ort_sys::OrtKernelContext>,
+	/// Indices of the outputs currently borrowed by a [`KernelOutput`].
+	borrowed_outputs: RefCell<Vec<usize>>
 }
 
 impl KernelContext {
 	pub(crate) fn new(ctx: *mut ort_sys::OrtKernelContext) -> Self {
 		Self {
-			ptr: NonNull::from(unsafe { &mut *ctx })
+			ptr: NonNull::from(unsafe { &mut *ctx }),
+			borrowed_outputs: RefCell::new(Vec::new())
 		}
 	}
 
@@ -225,28 +410,207 @@ impl KernelContext {
 		Ok(NonNull::new(value_ptr).map(|c| ValueRefMut::new(unsafe { Value::from_ptr_nodrop(c, None) })))
 	}
 
+	/// Allocates output `idx` with the same shape as input `like_input`, returning a [`KernelOutput`] which
+	/// dereferences to a mutable slice of the output's data.
+	///
+	/// This is a shortcut for elementwise operators, where the output always has the same shape as an input:
+	/// ```ignore
+	/// let (_, x) = ctx.input(0)?.ok_or_else(|| Error::new("missing input"))?.try_extract_raw_tensor::<f32>()?;
+	/// let mut z = ctx.output_like_mut::<f32>(0, 0)?;
+	/// for (z, x) in z.iter_mut().zip(x) {
+	/// 	*z = x.abs();
+	/// }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - Input `like_input` or output `idx` is not present.
+	/// - The output is not a tensor of element type `T`, or is not allocated in CPU memory.
+	/// - Output `idx` is already borrowed by another [`KernelOutput`] which has not yet been dropped.
+	pub fn output_like_mut<T: PrimitiveTensorElementType>(&self, idx: usize, like_input: usize) -> Result<KernelOutput<'_, T>> {
+		if self.borrowed_outputs.borrow().contains(&idx) {
+			return Err(Error::new(format!("Output {idx} is already borrowed")));
+		}
+
+		let input = self
+			.input(like_input)?
+			.ok_or_else(|| Error::new(format!("Input {like_input} is not present")))?;
+		let shape = input.shape()?;
+		let mut output = self
+			.output(idx, shape)?
+			.ok_or_else(|| Error::new(format!("Output {idx} is not present")))?;
+		let (_, data) = output.try_extract_raw_tensor_mut::<T>()?;
+		let (data, len) = (data.as_mut_ptr(), data.len());
+
+		self.borrowed_outputs.borrow_mut().push(idx);
+		Ok(KernelOutput {
+			ctx: self,
+			idx,
+			data,
+			len,
+			_value: output
+		})
+	}
+
+	/// Returns views of every input of this kernel as tensors of element type `T`.
+	///
+	/// This is a shortcut for variadic operators whose inputs all share the same element type:
+	/// ```ignore
+	/// let inputs = ctx.inputs_as::<f32>()?;
+	/// let sum = inputs.iter().fold(ndarray::ArrayD::zeros(inputs[0].shape()), |acc, x| acc + x);
+	/// ctx.set_output_array(0, sum.view())?;
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - Any input is not present (i.e. an omitted optional input).
+	/// - Any input is not a tensor of element type `T`, or is not allocated in CPU memory.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn inputs_as<T: PrimitiveTensorElementType>(&self) -> Result<Vec<ndarray::ArrayViewD<'_, T>>> {
+		(0..self.num_inputs()?)
+			.map(|idx| {
+				let input = self.input(idx)?.ok_or_else(|| Error::new(format!("Input {idx} is not present")))?;
+				let (shape, data) = input.try_extract_raw_tensor::<T>()?;
+				// the input's data is owned by the kernel context, not the `ValueRef`, so it remains valid for the lifetime of
+				// `self` after `input` is dropped
+				let data = unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) };
+				let shape = shape.into_iter().map(|d| d as usize).collect::<Vec<_>>();
+				Ok(ndarray::ArrayView::from_shape(ndarray::IxDyn(&shape), data).expect("Shape didn't match tensor contents"))
+			})
+			.collect()
+	}
+
+	/// Allocates output `idx` with the shape of `array` and copies `array`'s data into it.
+	///
+	/// This is a shortcut for kernels which compute their result as an [`ndarray`] array:
+	/// ```ignore
+	/// let inputs = ctx.inputs_as::<f32>()?;
+	/// ctx.set_output_array(0, inputs[0].t())?;
+	/// ```
+	///
+	/// `array` may have any memory layout; arrays that are not in standard (row-major, contiguous) layout, like the
+	/// transposed view above, are copied into standard layout first.
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - Output `idx` is not present.
+	/// - The output's element type is not `T`, or the output is not allocated in CPU memory.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn set_output_array<T: PrimitiveTensorElementType + Clone, D: ndarray::Dimension>(
+		&self,
+		idx: usize,
+		array: ndarray::ArrayView<'_, T, D>
+	) -> Result<()> {
+		let array = array.as_standard_layout();
+		let shape = array.shape().iter().map(|&d| d as i64).collect::<Vec<_>>();
+		let mut output = self
+			.output(idx, shape)?
+			.ok_or_else(|| Error::new(format!("Output {idx} is not present")))?;
+		let (_, data) = output.try_extract_raw_tensor_mut::<T>()?;
+		data.clone_from_slice(array.as_slice().expect("standard layout arrays should be contiguous"));
+		Ok(())
+	}
+
+	/// Copies input `in_idx` to output `out_idx`, allocating the output with the same shape as the input.
+	///
+	/// The output must have the same element type as the input. String tensors are supported; other tensors must be
+	/// allocated in CPU-accessible memory. The output receives its own copy of the data, so it can be freely mutated by
+	/// downstream nodes.
+	pub fn copy_input_to_output(&self, in_idx: usize, out_idx: usize) -> Result<()> {
+		let input = self.input(in_idx)?.ok_or_else(|| Error::new(format!("Input {in_idx} is not present")))?;
+		let (ty, shape) = match input.try_dtype()? {
+			ValueType::Tensor { ty, dimensions } => (ty, dimensions),
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot copy input {in_idx} of type {t}; expected a tensor")))
+		};
+		let mut output = self
+			.output(out_idx, shape)?
+			.ok_or_else(|| Error::new(format!("Output {out_idx} is not present")))?;
+		match output.try_dtype()? {
+			ValueType::Tensor { ty: out_ty, .. } if out_ty == ty => {}
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot copy input of type Tensor<{ty}> to output of type {t}")))
+		}
+
+		if ty == TensorElementType::String {
+			let (_, strings) = input.try_extract_raw_string_tensor()?;
+			// `FillStringTensor` requires null-terminated strings
+			let strings = strings
+				.into_iter()
+				.map(CString::new)
+				.collect::<Result<Vec<_>, _>>()
+				.map_err(Error::wrap)?;
+			let string_pointers = strings.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
+			ortsys![unsafe FillStringTensor(output.ptr(), string_pointers.as_ptr(), string_pointers.len() as _)?];
+			return Ok(());
+		}
+
+		for (value, name) in [(&*input, "input"), (&*output, "output")] {
+			let mem = value.memory_info();
+			if !mem.is_cpu_accessible() {
+				return Err(Error::new(format!("Cannot copy {name} on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
+			}
+		}
+		let len = input.byte_size()?;
+		if len > 0 {
+			let src = input.data_ptr()?;
+			let dst = output.data_ptr_mut()?;
+			unsafe { ptr::copy_nonoverlapping(src.cast::<u8>(), dst.cast::<u8>(), len) };
+		}
+		Ok(())
+	}
+
+	/// Returns the number of inputs this node was given, which may differ between nodes of variadic operators.
+	#[doc(alias = "input_count")]
 	pub fn num_inputs(&self) -> Result<usize> {
 		let mut num: ort_sys::size_t = 0;
 		ortsys![unsafe KernelContext_GetInputCount(self.ptr.as_ptr(), &mut num)?];
 		Ok(num as _)
 	}
 
+	/// Returns the number of outputs this node was given, which may differ between nodes of variadic operators.
+	#[doc(alias = "output_count")]
 	pub fn num_outputs(&self) -> Result<usize> {
 		let mut num: ort_sys::size_t = 0;
 		ortsys![unsafe KernelContext_GetOutputCount(self.ptr.as_ptr(), &mut num)?];
 		Ok(num as _)
 	}
 
+	/// Returns an allocator for memory described by `memory_info` from ONNX Runtime's own arena, e.g. for scratch
+	/// buffers needed during [`Kernel::compute`]. The allocator is released when it is dropped.
+	///
+	/// The allocator is owned by the kernel context, so it (and any memory allocated with it) must not be used after
+	/// the `compute` call that obtained it returns; allocate per invocation rather than storing it in the kernel.
+	#[doc(alias = "get_allocator")]
 	pub fn allocator(&self, memory_info: &MemoryInfo) -> Result<Allocator> {
 		let mut allocator_ptr = ptr::null_mut();
 		ortsys![unsafe KernelContext_GetAllocator(self.ptr.as_ptr(), memory_info.ptr.as_ptr(), &mut allocator_ptr)?];
 		Ok(unsafe { Allocator::from_raw_unchecked(allocator_ptr) })
 	}
 
-	pub fn get_resource(&self, id: ort_sys::c_int, version: ort_sys::c_int) -> Result<Option<NonNull<ort_sys::c_void>>> {
+	/// Returns a raw handle to a resource managed by the execution provider this kernel is running on, e.g. a CUDA
+	/// stream or cuDNN handle, for interop with libraries that need to share the provider's state.
+	///
+	/// Resources are identified by an `id` and the `version` of the provider's resource definitions, both of which are
+	/// defined in ONNX Runtime's headers:
+	/// - IDs are offsets from a per-provider base defined in `onnxruntime/core/providers/resource.h`: `0` for the CPU
+	///   provider, `10000` for CUDA, `20000` for DirectML, `30000` for ROCm, and `10000000` for custom providers. The
+	///   IDs of individual resources are listed in each provider's header, e.g. `CudaResource` in
+	///   `onnxruntime/core/providers/cuda/cuda_resource.h`, where `cuda_stream_t` is `10000`.
+	/// - The version is the `ORT_*_RESOUCE_VERSION` constant (sic) defined in the same provider header, e.g.
+	///   `ORT_CUDA_RESOUCE_VERSION`. Pass the version of the headers the resource IDs were taken from; providers reject
+	///   versions newer than their own.
+	///
+	/// The meaning of the returned pointer depends entirely on the resource, and it is only valid for the duration of
+	/// the `compute` call.
+	///
+	/// # Errors
+	/// Returns an error if the provider does not recognize the resource `id` or `version`, or if the provider has no
+	/// such resource (i.e. it returned a null pointer).
+	pub fn get_resource(&self, id: ort_sys::c_int, version: ort_sys::c_int) -> Result<NonNull<ort_sys::c_void>> {
 		let mut resource_ptr: *mut ort_sys::c_void = ptr::null_mut();
 		ortsys![unsafe KernelContext_GetResource(self.ptr.as_ptr(), version, id, &mut resource_ptr)?];
-		Ok(NonNull::new(resource_ptr))
+		NonNull::new(resource_ptr).ok_or_else(|| Error::new(format!("Execution provider returned no resource for ID {id} (version {version})")))
 	}
 
 	pub fn par_for<F>(&self, total: usize, max_num_batches: usize, f: F) -> Result<()>
@@ -283,11 +647,111 @@ impl KernelContext {
 	/// Returns a pointer to the GPU compute stream (i.e. `cudaStream_t`) used by the execution provider, if this
 	/// kernel's operator was configured to use said execution provider (see
 	/// [`super::Operator::execution_provider_type`]).
+	///
+	/// Returns `None` for kernels running on the CPU execution provider, which has no compute stream. Kernels that
+	/// launch their own GPU work should enqueue it on this stream so that it is ordered with the rest of the graph.
+	#[doc(alias = "gpu_compute_stream")]
 	pub fn compute_stream(&self) -> Result<Option<NonNull<ort_sys::c_void>>> {
 		let mut stream_ptr: *mut ort_sys::c_void = ptr::null_mut();
 		ortsys![unsafe KernelContext_GetGPUComputeStream(self.ptr.as_ptr(), &mut stream_ptr)?];
 		Ok(NonNull::new(stream_ptr))
 	}
+
+	/// Returns the session's logger, which can be used to emit messages from within [`Kernel::compute`] that are
+	/// interleaved with (and filtered by the same severity level as) the rest of ONNX Runtime's logs.
+	///
+	/// ```ignore
+	/// let logger = ctx.get_logger()?;
+	/// logger.log(LogLevel::Info, "computing", file!(), line!())?;
+	/// ```
+	pub fn get_logger(&self) -> Result<Logger<'_>> {
+		let mut logger_ptr: *const ort_sys::OrtLogger = ptr::null();
+		ortsys![unsafe KernelContext_GetLogger(self.ptr.as_ptr(), &mut logger_ptr)?; nonNull(logger_ptr)];
+		Ok(Logger {
+			ptr: NonNull::from(unsafe { &*logger_ptr }),
+			_context: PhantomData
+		})
+	}
+}
+
+/// The severity of a message logged through a [`Logger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+	/// Verbose informational messages (least severe).
+	Verbose,
+	/// Informational messages.
+	Info,
+	/// Warning messages.
+	Warning,
+	/// Error messages.
+	Error,
+	/// Fatal error messages (most severe).
+	Fatal
+}
+
+impl From<LogLevel> for ort_sys::OrtLoggingLevel {
+	fn from(val: LogLevel) -> Self {
+		match val {
+			LogLevel::Verbose => ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_VERBOSE,
+			LogLevel::Info => ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_INFO,
+			LogLevel::Warning => ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_WARNING,
+			LogLevel::Error => ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_ERROR,
+			LogLevel::Fatal => ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_FATAL
+		}
+	}
+}
+
+impl From<ort_sys::OrtLoggingLevel> for LogLevel {
+	fn from(val: ort_sys::OrtLoggingLevel) -> Self {
+		match val {
+			ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_VERBOSE => LogLevel::Verbose,
+			ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_INFO => LogLevel::Info,
+			ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_WARNING => LogLevel::Warning,
+			ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_ERROR => LogLevel::Error,
+			ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_FATAL => LogLevel::Fatal
+		}
+	}
+}
+
+/// ONNX Runtime's logger for a custom operator kernel, obtained via [`KernelContext::get_logger`].
+///
+/// The logger is owned by the kernel context, so it cannot outlive the `compute` call that obtained it.
+pub struct Logger<'c> {
+	ptr: NonNull<ort_sys::OrtLogger>,
+	_context: PhantomData<&'c KernelContext>
+}
+
+impl<'c> Logger<'c> {
+	/// Logs `message` with the given severity, attributing it to line `line` of source file `file` (typically
+	/// [`file!()`] and [`line!()`]).
+	///
+	/// Messages less severe than the session's configured severity level are discarded by ONNX Runtime; use
+	/// [`Logger::severity_level`] to avoid formatting messages which would not be logged.
+	///
+	/// # Errors
+	/// May return an error if `message` contains a null byte.
+	pub fn log(&self, severity: LogLevel, message: impl AsRef<str>, file: impl AsRef<Path>, line: u32) -> Result<()> {
+		let message = CString::new(message.as_ref())?;
+		let file = crate::util::path_to_os_char(file);
+		ortsys![
+			unsafe Logger_LogMessage(
+				self.ptr.as_ptr(),
+				severity.into(),
+				message.as_ptr(),
+				file.as_ptr(),
+				line.try_into().unwrap_or(ort_sys::c_int::MAX),
+				b"\0".as_ptr().cast()
+			)?
+		];
+		Ok(())
+	}
+
+	/// Returns the minimum severity of messages this logger will emit.
+	pub fn severity_level(&self) -> Result<LogLevel> {
+		let mut level = ort_sys::OrtLoggingLevel::ORT_LOGGING_LEVEL_VERBOSE;
+		ortsys![unsafe Logger_GetLoggingSeverityLevel(self.ptr.as_ptr(), &mut level)?];
+		Ok(level.into())
+	}
 }
 
 extern "C" fn parallel_for_cb(user_data: *mut c_void, iterator: ort_sys::size_t) {
@@ -82,7 +82,8 @@ impl Operator for DummyOperator {
 pub struct OperatorDomain {
 	ptr: NonNull<ort_sys::OrtCustomOpDomain>,
 	_name: CString,
-	operators: Vec<ErasedBoundOperator>
+	operators: Vec<ErasedBoundOperator>,
+	operator_names: Vec<&'static str>
 }
 
 impl OperatorDomain {
@@ -93,7 +94,8 @@ impl OperatorDomain {
 		Ok(Self {
 			_name: name,
 			ptr: NonNull::from(unsafe { &mut *ptr }),
-			operators: Vec::new()
+			operators: Vec::new(),
+			operator_names: Vec::new()
 		})
 	}
 
@@ -110,9 +112,20 @@ impl OperatorDomain {
 		ortsys![unsafe CustomOpDomain_Add(self.ptr.as_ptr(), bound.op_ptr())?];
 
 		self.operators.push(bound);
+		if !self.operator_names.contains(&name) {
+			self.operator_names.push(name);
+		}
 
 		Ok(self)
 	}
+
+	/// Returns the names of the operators added to this domain so far, in the order they were added.
+	///
+	/// Operators which share the same name (i.e. variants of the same operator with different input types) are only
+	/// listed once.
+	pub fn operator_names(&self) -> Vec<String> {
+		self.operator_names.iter().map(|name| name.to_string()).collect()
+	}
 }
 
 impl Drop for OperatorDomain {
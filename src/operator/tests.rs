@@ -1,6 +1,9 @@
-use ndarray::{Array2, arr2};
+use ndarray::{Array1, Array2, arr2};
 
-use crate::{Kernel, KernelAttributes, KernelContext, Operator, OperatorDomain, OperatorInput, OperatorOutput, Result, Session, TensorElementType};
+use crate::{
+	DynTensor, InputOutputCharacteristic, Kernel, KernelAttributes, KernelContext, LogLevel, Operator, OperatorDomain, OperatorInput, OperatorOutput, Result,
+	Session, TensorElementType
+};
 
 struct CustomOpOne;
 struct CustomOpOneKernel;
@@ -92,3 +95,393 @@ fn test_custom_ops() -> crate::Result<()> {
 
 	Ok(())
 }
+
+/// Implements the same `CustomOpTwo` as above, but allocates its output via [`KernelContext::output_like_mut`].
+struct OutputLikeOp;
+struct OutputLikeKernel;
+
+impl Operator for OutputLikeOp {
+	type Kernel = OutputLikeKernel;
+
+	fn name() -> &'static str {
+		"CustomOpTwo"
+	}
+
+	fn create_kernel(_: &KernelAttributes) -> crate::Result<Self::Kernel> {
+		Ok(OutputLikeKernel)
+	}
+
+	fn inputs() -> Vec<OperatorInput> {
+		vec![OperatorInput::required(TensorElementType::Float32)]
+	}
+
+	fn outputs() -> Vec<OperatorOutput> {
+		vec![OperatorOutput::required(TensorElementType::Int32)]
+	}
+}
+
+impl Kernel for OutputLikeKernel {
+	fn compute(&mut self, ctx: &KernelContext) -> crate::Result<()> {
+		let x = ctx.input(0)?.ok_or_else(|| crate::Error::new("missing input"))?;
+		let (_, x) = x.try_extract_raw_tensor::<f32>()?;
+		let mut z = ctx.output_like_mut::<i32>(0, 0)?;
+		// the output can't be borrowed twice...
+		assert!(ctx.output_like_mut::<i32>(0, 0).is_err());
+		for (i, z) in z.iter_mut().enumerate() {
+			*z = (x[i] * i as f32) as i32;
+		}
+		drop(z);
+		// ...but can be borrowed again once the first view is dropped
+		assert!(ctx.output_like_mut::<i32>(0, 0).is_ok());
+		Ok(())
+	}
+}
+
+#[test]
+fn test_custom_op_output_like_mut() -> crate::Result<()> {
+	let session = Session::builder()?
+		.with_operators(OperatorDomain::new("test.customop")?.add::<CustomOpOne>()?.add::<OutputLikeOp>()?)?
+		.commit_from_file("tests/data/custom_op_test.onnx")?;
+
+	let values = session.run(crate::inputs![Array2::<f32>::zeros((3, 5)), Array2::<f32>::ones((3, 5))]?)?;
+	assert_eq!(values[0].try_extract_tensor::<i32>()?, arr2(&[[0, 1, 0, 3, 0], [5, 0, 7, 0, 9], [0, 11, 0, 13, 0]]).view().into_dyn());
+
+	Ok(())
+}
+
+struct AttributeOp;
+struct AttributeKernel {
+	values: Vec<i64>
+}
+
+impl Operator for AttributeOp {
+	type Kernel = AttributeKernel;
+
+	fn name() -> &'static str {
+		"AttributeOp"
+	}
+
+	fn create_kernel(attributes: &KernelAttributes) -> crate::Result<Self::Kernel> {
+		let axis = attributes
+			.get::<i64>("axis")
+			.ok_or_else(|| crate::Error::new("missing attribute `axis`"))?;
+		let mode = attributes
+			.get::<String>("mode")
+			.ok_or_else(|| crate::Error::new("missing attribute `mode`"))?;
+		assert_eq!(mode, "linear");
+		// missing attributes and attributes of the wrong type are `None`
+		assert!(attributes.get::<i64>("mode").is_none());
+		assert!(attributes.get::<String>("axis").is_none());
+		assert!(attributes.get::<String>("missing").is_none());
+
+		let pads = attributes
+			.get::<Vec<i64>>("pads")
+			.ok_or_else(|| crate::Error::new("missing attribute `pads`"))?;
+		assert_eq!(attributes.get::<Vec<f32>>("scales"), Some(vec![0.5, 2.0]));
+		assert_eq!(attributes.get::<Vec<i64>>("empty"), Some(vec![]));
+		assert!(attributes.get::<Vec<f32>>("pads").is_none());
+		assert!(attributes.get::<Vec<i64>>("missing").is_none());
+		Ok(AttributeKernel {
+			values: [&[axis, mode.len() as i64][..], &pads].concat()
+		})
+	}
+
+	fn inputs() -> Vec<OperatorInput> {
+		vec![OperatorInput::required(TensorElementType::Float32)]
+	}
+
+	fn outputs() -> Vec<OperatorOutput> {
+		vec![OperatorOutput::required(TensorElementType::Int64)]
+	}
+}
+
+impl Kernel for AttributeKernel {
+	fn compute(&mut self, ctx: &KernelContext) -> crate::Result<()> {
+		let mut y = ctx
+			.output(0, [self.values.len() as i64])?
+			.ok_or_else(|| crate::Error::new("missing output"))?;
+		let (_, y) = y.try_extract_raw_tensor_mut::<i64>()?;
+		y.copy_from_slice(&self.values);
+		Ok(())
+	}
+}
+
+#[test]
+fn test_custom_op_attributes() -> crate::Result<()> {
+	let session = Session::builder()?
+		.with_operators(OperatorDomain::new("test.customop")?.add::<AttributeOp>()?)?
+		.commit_from_file("tests/data/custom_op_attributes.onnx")?;
+
+	let values = session.run(crate::inputs![Array1::<f32>::zeros(1)]?)?;
+	assert_eq!(values[0].try_extract_raw_tensor::<i64>()?.1, &[3, 6, 1, 2, 3, 4]);
+
+	Ok(())
+}
+
+struct TensorAttributeOp;
+struct TensorAttributeKernel {
+	table: DynTensor
+}
+
+impl Operator for TensorAttributeOp {
+	type Kernel = TensorAttributeKernel;
+
+	fn name() -> &'static str {
+		"TensorAttributeOp"
+	}
+
+	fn create_kernel(attributes: &KernelAttributes) -> crate::Result<Self::Kernel> {
+		let table = attributes
+			.get_tensor("table")
+			.ok_or_else(|| crate::Error::new("missing attribute `table`"))?;
+		assert!(attributes.get_tensor("missing").is_none());
+		Ok(TensorAttributeKernel { table })
+	}
+
+	fn inputs() -> Vec<OperatorInput> {
+		vec![OperatorInput::required(TensorElementType::Float32)]
+	}
+
+	fn outputs() -> Vec<OperatorOutput> {
+		vec![OperatorOutput::required(TensorElementType::Float32)]
+	}
+}
+
+impl Kernel for TensorAttributeKernel {
+	fn compute(&mut self, ctx: &KernelContext) -> crate::Result<()> {
+		let (shape, table) = self.table.try_extract_raw_tensor::<f32>()?;
+		let mut y = ctx.output(0, shape)?.ok_or_else(|| crate::Error::new("missing output"))?;
+		let (_, y) = y.try_extract_raw_tensor_mut::<f32>()?;
+		y.copy_from_slice(table);
+		Ok(())
+	}
+}
+
+#[test]
+fn test_custom_op_tensor_attribute() -> crate::Result<()> {
+	let session = Session::builder()?
+		.with_operators(OperatorDomain::new("test.customop")?.add::<TensorAttributeOp>()?)?
+		.commit_from_file("tests/data/custom_op_tensor_attribute.onnx")?;
+
+	let values = session.run(crate::inputs![Array1::<f32>::zeros(1)]?)?;
+	assert_eq!(values[0].try_extract_raw_tensor::<f32>()?, (vec![3], &[0.5, -1.0, 2.0][..]));
+
+	Ok(())
+}
+
+struct TransposeArrayOp;
+struct TransposeArrayKernel;
+
+impl Operator for TransposeArrayOp {
+	type Kernel = TransposeArrayKernel;
+
+	fn name() -> &'static str {
+		"TransposeArrayOp"
+	}
+
+	fn create_kernel(_: &KernelAttributes) -> crate::Result<Self::Kernel> {
+		Ok(TransposeArrayKernel)
+	}
+
+	fn inputs() -> Vec<OperatorInput> {
+		vec![OperatorInput::required(TensorElementType::Float32)]
+	}
+
+	fn outputs() -> Vec<OperatorOutput> {
+		vec![OperatorOutput::required(TensorElementType::Float32)]
+	}
+}
+
+impl Kernel for TransposeArrayKernel {
+	fn compute(&mut self, ctx: &KernelContext) -> crate::Result<()> {
+		let inputs = ctx.inputs_as::<f32>()?;
+		// the transposed view is not contiguous, so this exercises the copy into standard layout
+		ctx.set_output_array(0, inputs[0].t())
+	}
+}
+
+#[test]
+fn test_custom_op_set_output_array() -> crate::Result<()> {
+	let session = Session::builder()?
+		.with_operators(OperatorDomain::new("test.customop")?.add::<TransposeArrayOp>()?)?
+		.commit_from_file("tests/data/custom_op_set_output_array.onnx")?;
+
+	let values = session.run(crate::inputs![arr2(&[[1.0_f32, 2.0, 3.0], [4.0, 5.0, 6.0]])]?)?;
+	assert_eq!(values[0].try_extract_raw_tensor::<f32>()?, (vec![3, 2], &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0][..]));
+
+	Ok(())
+}
+
+struct ConstantInputOp;
+struct ConstantInputKernel {
+	values: Vec<f32>
+}
+
+impl Operator for ConstantInputOp {
+	type Kernel = ConstantInputKernel;
+
+	fn name() -> &'static str {
+		"ConstantInputOp"
+	}
+
+	fn create_kernel(attributes: &KernelAttributes) -> crate::Result<Self::Kernel> {
+		let count = attributes.constant_input_count()?;
+		// `x` is a graph input, `w` is an initializer
+		assert!(attributes.constant_input(0)?.is_none());
+		let w = attributes
+			.constant_input(1)?
+			.ok_or_else(|| crate::Error::new("input `w` is not constant"))?;
+		Ok(ConstantInputKernel {
+			values: [&[count as f32][..], w.try_extract_raw_tensor::<f32>()?.1].concat()
+		})
+	}
+
+	fn inputs() -> Vec<OperatorInput> {
+		vec![OperatorInput::required(TensorElementType::Float32), OperatorInput::required(TensorElementType::Float32)]
+	}
+
+	fn outputs() -> Vec<OperatorOutput> {
+		vec![OperatorOutput::required(TensorElementType::Float32)]
+	}
+}
+
+impl Kernel for ConstantInputKernel {
+	fn compute(&mut self, ctx: &KernelContext) -> crate::Result<()> {
+		let mut y = ctx
+			.output(0, [self.values.len() as i64])?
+			.ok_or_else(|| crate::Error::new("missing output"))?;
+		let (_, y) = y.try_extract_raw_tensor_mut::<f32>()?;
+		y.copy_from_slice(&self.values);
+		Ok(())
+	}
+}
+
+#[test]
+fn test_custom_op_constant_inputs() -> crate::Result<()> {
+	let session = Session::builder()?
+		.with_operators(OperatorDomain::new("test.customop")?.add::<ConstantInputOp>()?)?
+		.commit_from_file("tests/data/custom_op_constant_inputs.onnx")?;
+
+	let values = session.run(crate::inputs![Array1::<f32>::zeros(1)]?)?;
+	assert_eq!(values[0].try_extract_raw_tensor::<f32>()?.1, &[1.0, 1.5, 2.5]);
+
+	Ok(())
+}
+
+struct LoggingCustomOpOne;
+struct LoggingKernel;
+
+impl Operator for LoggingCustomOpOne {
+	type Kernel = LoggingKernel;
+
+	fn name() -> &'static str {
+		"CustomOpOne"
+	}
+
+	fn create_kernel(_: &KernelAttributes) -> crate::Result<Self::Kernel> {
+		Ok(LoggingKernel)
+	}
+
+	fn inputs() -> Vec<OperatorInput> {
+		vec![OperatorInput::required(TensorElementType::Float32), OperatorInput::required(TensorElementType::Float32)]
+	}
+
+	fn outputs() -> Vec<OperatorOutput> {
+		vec![OperatorOutput::required(TensorElementType::Float32)]
+	}
+}
+
+impl Kernel for LoggingKernel {
+	fn compute(&mut self, ctx: &KernelContext) -> crate::Result<()> {
+		let logger = ctx.get_logger()?;
+		logger.severity_level()?;
+		for level in [LogLevel::Verbose, LogLevel::Info, LogLevel::Warning, LogLevel::Error, LogLevel::Fatal] {
+			logger.log(level, format!("logging at {level:?}"), file!(), line!())?;
+		}
+		assert!(logger.log(LogLevel::Info, "null\0byte", file!(), line!()).is_err());
+		CustomOpOneKernel.compute(ctx)
+	}
+}
+
+#[test]
+fn test_custom_op_logger() -> crate::Result<()> {
+	let session = Session::builder()?
+		.with_operators(OperatorDomain::new("test.customop")?.add::<LoggingCustomOpOne>()?.add::<CustomOpTwo>()?)?
+		.commit_from_file("tests/data/custom_op_test.onnx")?;
+
+	let values = session.run(crate::inputs![Array2::<f32>::zeros((3, 5)), Array2::<f32>::ones((3, 5))]?)?;
+	assert_eq!(values[0].try_extract_tensor::<i32>()?, arr2(&[[0, 1, 0, 3, 0], [5, 0, 7, 0, 9], [0, 11, 0, 13, 0]]).view().into_dyn());
+
+	Ok(())
+}
+
+struct PanickingCustomOpOne;
+struct PanickingKernel;
+
+impl Operator for PanickingCustomOpOne {
+	type Kernel = PanickingKernel;
+
+	fn name() -> &'static str {
+		"CustomOpOne"
+	}
+
+	fn create_kernel(_: &KernelAttributes) -> crate::Result<Self::Kernel> {
+		Ok(PanickingKernel)
+	}
+
+	fn inputs() -> Vec<OperatorInput> {
+		vec![OperatorInput::required(TensorElementType::Float32), OperatorInput::required(TensorElementType::Float32)]
+	}
+
+	fn outputs() -> Vec<OperatorOutput> {
+		vec![OperatorOutput::required(TensorElementType::Float32)]
+	}
+}
+
+impl Kernel for PanickingKernel {
+	fn compute(&mut self, _: &KernelContext) -> crate::Result<()> {
+		panic!("kernel exploded");
+	}
+}
+
+#[test]
+fn test_custom_op_panic() -> crate::Result<()> {
+	let session = Session::builder()?
+		.with_operators(
+			OperatorDomain::new("test.customop")?
+				.add::<PanickingCustomOpOne>()?
+				.add::<CustomOpTwo>()?
+		)?
+		.commit_from_file("tests/data/custom_op_test.onnx")?;
+
+	let err = session
+		.run(crate::inputs![Array2::<f32>::zeros((3, 5)), Array2::<f32>::ones((3, 5))]?)
+		.expect_err("panicking kernel should fail the run");
+	assert!(err.to_string().contains("kernel exploded"));
+
+	Ok(())
+}
+
+#[test]
+fn test_operator_names() -> crate::Result<()> {
+	let domain = OperatorDomain::new("test.customop")?
+		.add::<CustomOpOne>()?
+		.add::<CustomOpTwo>()?
+		.add::<PanickingCustomOpOne>()?;
+	assert_eq!(domain.operator_names(), ["CustomOpOne", "CustomOpTwo"]);
+
+	Ok(())
+}
+
+#[test]
+fn test_operator_io_characteristics() {
+	let input = OperatorInput::new(TensorElementType::Int64, InputOutputCharacteristic::Optional);
+	assert_eq!(input.characteristic, InputOutputCharacteristic::Optional);
+	assert_eq!(input.r#type, Some(TensorElementType::Int64));
+
+	let output = OperatorOutput::new(TensorElementType::Float32, InputOutputCharacteristic::Variadic);
+	assert_eq!(output.characteristic, InputOutputCharacteristic::Variadic);
+	assert_eq!(output.r#type, Some(TensorElementType::Float32));
+	assert_eq!(output.variadic_min_arity, Some(1));
+	assert_eq!(output.variadic_homogeneity, Some(true));
+}
@@ -151,8 +151,11 @@ impl Session {
 	}
 
 	/// Returns a list of initializers which are overridable (i.e. also graph inputs).
-	#[must_use]
-	pub fn overridable_initializers(&self) -> Vec<OverridableInitializer> {
+	///
+	/// # Errors
+	/// Returns an error with [`crate::ErrorCode::UnsupportedTensorElementType`] if an initializer has an element type
+	/// which is not supported by this build of `ort`.
+	pub fn overridable_initializers(&self) -> Result<Vec<OverridableInitializer>> {
 		// can only fail if:
 		// - index is out of bounds (impossible because of the loop)
 		// - the model is not loaded (how could this even be possible?)
@@ -166,8 +169,8 @@ impl Session {
 				let name = unsafe { CStr::from_ptr(name) }.to_string_lossy().into_owned();
 				let mut typeinfo_ptr: *mut ort_sys::OrtTypeInfo = std::ptr::null_mut();
 				ortsys![unsafe SessionGetOverridableInitializerTypeInfo(self.ptr(), i, &mut typeinfo_ptr).expect("infallible")];
-				let dtype = ValueType::from_type_info(typeinfo_ptr);
-				OverridableInitializer { name, dtype }
+				let dtype = ValueType::from_type_info(typeinfo_ptr)?;
+				Ok(OverridableInitializer { name, dtype })
 			})
 			.collect()
 	}
@@ -595,6 +598,6 @@ mod dangerous {
 		status_to_result(status)?;
 		assert_non_null_pointer(typeinfo_ptr, "TypeInfo")?;
 
-		Ok(ValueType::from_type_info(typeinfo_ptr))
+		ValueType::from_type_info(typeinfo_ptr)
 	}
 }
@@ -20,6 +20,7 @@ mod types;
 
 #[cfg(feature = "ndarray")]
 pub use self::ndarray::ArrayExtensions;
-pub use self::types::{IntoTensorElementType, PrimitiveTensorElementType, TensorElementType, Utf8Data};
+pub(crate) use self::types::SUPPORTED_ELEMENT_TYPES;
+pub use self::types::{IntoTensorElementType, NumericTensorElementType, PrimitiveTensorElementType, TensorElementType, Utf8Data, supported_element_types};
 #[cfg(feature = "ndarray")]
 pub(crate) use self::types::{extract_primitive_array, extract_primitive_array_mut};
@@ -92,6 +92,18 @@ mod tests {
 		assert!(diff.iter().all(|d| d.abs() < 1.0e-7));
 	}
 
+	#[test]
+	fn softmax_batched() {
+		let array = arr2(&[[1.0_f32, 2.0, 3.0], [0.0_f32, 0.0, 0.0]]);
+
+		let softmax = array.softmax(ndarray::Axis(1));
+
+		// each sample is normalized independently
+		let expected_softmax = arr2(&[[0.09003057, 0.24472847, 0.66524096], [0.33333334, 0.33333334, 0.33333334]]);
+		let diff = softmax - expected_softmax;
+		assert!(diff.iter().all(|d| d.abs() < 1.0e-7));
+	}
+
 	#[test]
 	fn softmax_3d() {
 		let array = arr3(&[
@@ -1,9 +1,10 @@
-use std::fmt;
 #[cfg(feature = "ndarray")]
 use std::ptr;
+use std::{any::Any, borrow::Cow, fmt, sync::Arc};
 
+use crate::error::{Error, ErrorCode, Result};
 #[cfg(feature = "ndarray")]
-use crate::{error::Result, ortsys};
+use crate::ortsys;
 
 /// Enum mapping ONNX Runtime's supported tensor data types.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -39,17 +40,404 @@ pub enum TensorElementType {
 	/// Brain 16-bit floating point number, equivalent to [`half::bf16`] (requires the `half` feature).
 	#[cfg(feature = "half")]
 	#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
-	Bfloat16
+	Bfloat16,
+	/// 64-bit complex number with 32-bit real and imaginary parts, equivalent to [`num_complex::Complex<f32>`]
+	/// (requires the `complex` feature).
+	#[cfg(feature = "complex")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "complex")))]
+	Complex64,
+	/// 128-bit complex number with 64-bit real and imaginary parts, equivalent to [`num_complex::Complex<f64>`]
+	/// (requires the `complex` feature).
+	#[cfg(feature = "complex")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "complex")))]
+	Complex128,
+	/// 8-bit floating point number with 4 exponent bits and 3 mantissa bits, which can represent NaN but not infinity,
+	/// equivalent to [`float8::F8E4M3`] (requires the `fp8` feature).
+	#[cfg(feature = "fp8")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fp8")))]
+	Float8E4M3FN,
+	/// 8-bit floating point number with 5 exponent bits and 2 mantissa bits, equivalent to [`float8::F8E5M2`] (requires
+	/// the `fp8` feature).
+	#[cfg(feature = "fp8")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "fp8")))]
+	Float8E5M2
 }
 
+impl TensorElementType {
+	/// Returns the size in bytes of a single element of this type.
+	///
+	/// Strings are variable-length and have no fixed element size, so this returns `0` for
+	/// [`TensorElementType::String`]; use [`TensorElementType::try_byte_size`] to distinguish them.
+	///
+	/// ```
+	/// # use ort::TensorElementType;
+	/// assert_eq!(TensorElementType::Float32.byte_size(), 4);
+	/// assert_eq!(TensorElementType::Bool.byte_size(), 1);
+	/// assert_eq!(TensorElementType::String.byte_size(), 0);
+	/// ```
+	pub fn byte_size(&self) -> usize {
+		self.try_byte_size().unwrap_or(0)
+	}
+
+	/// Returns the size in bytes of a single element of this type, or `None` for variable-length strings.
+	///
+	/// ```
+	/// # use ort::TensorElementType;
+	/// assert_eq!(TensorElementType::Int64.try_byte_size(), Some(8));
+	/// assert_eq!(TensorElementType::String.try_byte_size(), None);
+	/// ```
+	pub fn try_byte_size(&self) -> Option<usize> {
+		match self {
+			TensorElementType::Bool | TensorElementType::Int8 | TensorElementType::Uint8 => Some(1),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN | TensorElementType::Float8E5M2 => Some(1),
+			TensorElementType::Int16 | TensorElementType::Uint16 => Some(2),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 | TensorElementType::Bfloat16 => Some(2),
+			TensorElementType::Float32 | TensorElementType::Int32 | TensorElementType::Uint32 => Some(4),
+			TensorElementType::Float64 | TensorElementType::Int64 | TensorElementType::Uint64 => Some(8),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 => Some(8),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex128 => Some(16),
+			TensorElementType::String => None
+		}
+	}
+
+	/// Returns the element type corresponding to the concrete type of `value`, or `None` if it is not a supported
+	/// tensor element type.
+	///
+	/// ```
+	/// # use std::any::Any;
+	/// # use ort::TensorElementType;
+	/// let values: Vec<Box<dyn Any>> =
+	/// 	vec![Box::new(1.0_f32), Box::new(42_i64), Box::new("hello".to_string()), Box::new('x')];
+	/// let types: Vec<_> = values.iter().map(|v| TensorElementType::from_std_any(v.as_ref())).collect();
+	/// assert_eq!(
+	/// 	types,
+	/// 	[Some(TensorElementType::Float32), Some(TensorElementType::Int64), Some(TensorElementType::String), None]
+	/// );
+	/// ```
+	pub fn from_std_any(value: &dyn Any) -> Option<TensorElementType> {
+		macro_rules! classify {
+			($($(#[$meta:meta])* $type_:ty),+) => {
+				$(
+					$(#[$meta])*
+					if value.is::<$type_>() {
+						return Some(<$type_>::into_tensor_element_type());
+					}
+				)+
+			};
+		}
+
+		classify!(
+			f32,
+			u8,
+			i8,
+			u16,
+			i16,
+			i32,
+			i64,
+			String,
+			bool,
+			#[cfg(feature = "half")]
+			half::f16,
+			f64,
+			u32,
+			u64,
+			#[cfg(feature = "half")]
+			half::bf16,
+			#[cfg(feature = "complex")]
+			num_complex::Complex<f32>,
+			#[cfg(feature = "complex")]
+			num_complex::Complex<f64>,
+			#[cfg(feature = "fp8")]
+			float8::F8E4M3,
+			#[cfg(feature = "fp8")]
+			float8::F8E5M2
+		);
+		None
+	}
+
+	/// Returns `true` if every value of this type can be converted to `target` without any loss of precision or range,
+	/// e.g. `i16` to `i32` or `u8` to `f32`, but not `i64` to `i32` or `i32` to `f32`.
+	///
+	/// Booleans can be losslessly converted to any numeric type (as `0` or `1`), and every type can be losslessly
+	/// converted to itself. A real type can be losslessly converted to a complex type if it can be losslessly converted
+	/// to the complex type's parts (the imaginary part being zero), e.g. `f32` to `Complex64` but not `f64` to
+	/// `Complex64`. Complex types can only be converted to complex types with parts at least as wide, e.g. `Complex64`
+	/// to `Complex128`, never to real types. Strings can't be converted to or from any other type.
+	///
+	/// ```
+	/// # use ort::TensorElementType;
+	/// assert!(TensorElementType::Int16.can_losslessly_convert_to(TensorElementType::Int32));
+	/// assert!(TensorElementType::Uint16.can_losslessly_convert_to(TensorElementType::Float32));
+	/// assert!(!TensorElementType::Int64.can_losslessly_convert_to(TensorElementType::Int32));
+	/// assert!(!TensorElementType::Int32.can_losslessly_convert_to(TensorElementType::Float32));
+	/// assert!(!TensorElementType::Int8.can_losslessly_convert_to(TensorElementType::Uint64));
+	/// ```
+	pub fn can_losslessly_convert_to(&self, target: TensorElementType) -> bool {
+		if *self == target {
+			return true;
+		}
+
+		let (source, target) = (self.numeric_repr(), target.numeric_repr());
+		// a real number converts to a complex number with a zero imaginary part, so only the real part needs to fit
+		#[cfg(feature = "complex")]
+		let target = match (&source, target) {
+			(Some(source), Some(NumericRepr::Complex(significand_bits, exponent_bits))) if !matches!(source, NumericRepr::Complex(..)) => {
+				Some(NumericRepr::Float(significand_bits, exponent_bits))
+			}
+			(_, target) => target
+		};
+
+		match (source, target) {
+			(Some(NumericRepr::Bool), Some(_)) => true,
+			(Some(NumericRepr::Unsigned(bits)), Some(NumericRepr::Unsigned(target_bits))) => target_bits >= bits,
+			(Some(NumericRepr::Unsigned(bits)), Some(NumericRepr::Signed(target_bits))) => target_bits > bits,
+			(Some(NumericRepr::Signed(bits)), Some(NumericRepr::Signed(target_bits))) => target_bits >= bits,
+			// every integer is exactly representable if its magnitude fits in the float's significand
+			(Some(NumericRepr::Unsigned(bits)), Some(NumericRepr::Float(significand_bits, _))) => bits <= significand_bits,
+			(Some(NumericRepr::Signed(bits)), Some(NumericRepr::Float(significand_bits, _))) => bits - 1 <= significand_bits,
+			(Some(NumericRepr::Float(significand_bits, exponent_bits)), Some(NumericRepr::Float(target_significand_bits, target_exponent_bits))) => {
+				target_significand_bits >= significand_bits && target_exponent_bits >= exponent_bits
+			}
+			#[cfg(feature = "complex")]
+			(Some(NumericRepr::Complex(significand_bits, exponent_bits)), Some(NumericRepr::Complex(target_significand_bits, target_exponent_bits))) => {
+				target_significand_bits >= significand_bits && target_exponent_bits >= exponent_bits
+			}
+			_ => false
+		}
+	}
+
+	/// Returns the smallest and largest finite values representable by this type as `f64`s, or `None` for types
+	/// without a numeric range (strings and complex numbers). Booleans have the range `(0.0, 1.0)`.
+	///
+	/// Note that the limits of 64-bit integers are rounded to the nearest representable `f64`, so `i64::MAX` and
+	/// `u64::MAX` are reported as 2<sup>63</sup> and 2<sup>64</sup> respectively.
+	///
+	/// ```
+	/// # use ort::TensorElementType;
+	/// assert_eq!(TensorElementType::Int8.value_range(), Some((-128.0, 127.0)));
+	/// assert_eq!(TensorElementType::Uint8.value_range(), Some((0.0, 255.0)));
+	/// assert_eq!(TensorElementType::Float32.value_range(), Some((f32::MIN as f64, f32::MAX as f64)));
+	/// assert_eq!(TensorElementType::String.value_range(), None);
+	/// ```
+	pub fn value_range(&self) -> Option<(f64, f64)> {
+		macro_rules! range {
+			($t:ty) => {
+				(<$t>::MIN as f64, <$t>::MAX as f64)
+			};
+		}
+
+		Some(match self {
+			TensorElementType::Bool => (0.0, 1.0),
+			TensorElementType::Int8 => range!(i8),
+			TensorElementType::Int16 => range!(i16),
+			TensorElementType::Int32 => range!(i32),
+			TensorElementType::Int64 => range!(i64),
+			TensorElementType::Uint8 => range!(u8),
+			TensorElementType::Uint16 => range!(u16),
+			TensorElementType::Uint32 => range!(u32),
+			TensorElementType::Uint64 => range!(u64),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 => (half::f16::MIN.to_f64(), half::f16::MAX.to_f64()),
+			#[cfg(feature = "half")]
+			TensorElementType::Bfloat16 => (half::bf16::MIN.to_f64(), half::bf16::MAX.to_f64()),
+			TensorElementType::Float32 => range!(f32),
+			TensorElementType::Float64 => range!(f64),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN => (float8::F8E4M3::MIN.to_f64(), float8::F8E4M3::MAX.to_f64()),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E5M2 => (float8::F8E5M2::MIN.to_f64(), float8::F8E5M2::MAX.to_f64()),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 | TensorElementType::Complex128 => return None,
+			TensorElementType::String => return None
+		})
+	}
+
+	/// Returns `true` if this is a real floating-point type, i.e. [`TensorElementType::Float32`],
+	/// [`TensorElementType::Float64`], or one of the reduced-precision `f16`/`bf16`/8-bit float types.
+	///
+	/// ```
+	/// # use ort::TensorElementType;
+	/// assert!(TensorElementType::Float32.is_float());
+	/// assert!(!TensorElementType::Int32.is_float());
+	/// assert!(!TensorElementType::Bool.is_float());
+	/// ```
+	pub const fn is_float(&self) -> bool {
+		match self {
+			TensorElementType::Float32 | TensorElementType::Float64 => true,
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 | TensorElementType::Bfloat16 => true,
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN | TensorElementType::Float8E5M2 => true,
+			_ => false
+		}
+	}
+
+	/// Returns `true` if this is a signed or unsigned integer type. Booleans are not considered integers.
+	///
+	/// ```
+	/// # use ort::TensorElementType;
+	/// assert!(TensorElementType::Int64.is_integer());
+	/// assert!(TensorElementType::Uint8.is_integer());
+	/// assert!(!TensorElementType::Bool.is_integer());
+	/// ```
+	pub const fn is_integer(&self) -> bool {
+		matches!(
+			self,
+			TensorElementType::Int8
+				| TensorElementType::Int16
+				| TensorElementType::Int32
+				| TensorElementType::Int64
+				| TensorElementType::Uint8
+				| TensorElementType::Uint16
+				| TensorElementType::Uint32
+				| TensorElementType::Uint64
+		)
+	}
+
+	/// Returns `true` if this type can represent negative numbers, i.e. it is a signed integer type (`i8`..`i64`) or a
+	/// floating-point type. Unsigned integers, booleans, strings, and complex numbers are not signed.
+	///
+	/// ```
+	/// # use ort::TensorElementType;
+	/// assert!(TensorElementType::Int8.is_signed());
+	/// assert!(TensorElementType::Float32.is_signed());
+	/// assert!(!TensorElementType::Uint8.is_signed());
+	/// ```
+	pub const fn is_signed(&self) -> bool {
+		matches!(self, TensorElementType::Int8 | TensorElementType::Int16 | TensorElementType::Int32 | TensorElementType::Int64) || self.is_float()
+	}
+
+	/// Returns `true` if this is a numeric type, i.e. an integer, floating-point, or complex type. Booleans and strings
+	/// are not numeric.
+	///
+	/// ```
+	/// # use ort::TensorElementType;
+	/// assert!(TensorElementType::Uint16.is_numeric());
+	/// assert!(TensorElementType::Float64.is_numeric());
+	/// assert!(!TensorElementType::Bool.is_numeric());
+	/// assert!(!TensorElementType::String.is_numeric());
+	/// ```
+	pub const fn is_numeric(&self) -> bool {
+		match self {
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 | TensorElementType::Complex128 => true,
+			_ => self.is_integer() || self.is_float()
+		}
+	}
+
+	/// Returns the canonical ONNX name of this type as a tensor element, e.g. `tensor(float)` for
+	/// [`TensorElementType::Float32`], matching the type names shown by Netron and ONNX Runtime's Python API.
+	///
+	/// Note that the [`Display`](fmt::Display) implementation of [`TensorElementType`] prints the type's Rust
+	/// equivalent (e.g. `f32`) instead; use this to log types consistently with other ONNX tools.
+	///
+	/// ```
+	/// # use ort::TensorElementType;
+	/// assert_eq!(TensorElementType::Float32.onnx_name(), "tensor(float)");
+	/// assert_eq!(TensorElementType::Int64.onnx_name(), "tensor(int64)");
+	/// assert_eq!(TensorElementType::String.onnx_name(), "tensor(string)");
+	/// ```
+	pub const fn onnx_name(&self) -> &'static str {
+		match self {
+			TensorElementType::Float32 => "tensor(float)",
+			TensorElementType::Uint8 => "tensor(uint8)",
+			TensorElementType::Int8 => "tensor(int8)",
+			TensorElementType::Uint16 => "tensor(uint16)",
+			TensorElementType::Int16 => "tensor(int16)",
+			TensorElementType::Int32 => "tensor(int32)",
+			TensorElementType::Int64 => "tensor(int64)",
+			TensorElementType::String => "tensor(string)",
+			TensorElementType::Bool => "tensor(bool)",
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 => "tensor(float16)",
+			TensorElementType::Float64 => "tensor(double)",
+			TensorElementType::Uint32 => "tensor(uint32)",
+			TensorElementType::Uint64 => "tensor(uint64)",
+			#[cfg(feature = "half")]
+			TensorElementType::Bfloat16 => "tensor(bfloat16)",
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 => "tensor(complex64)",
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex128 => "tensor(complex128)",
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN => "tensor(float8e4m3fn)",
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E5M2 => "tensor(float8e5m2)"
+		}
+	}
+
+	fn numeric_repr(&self) -> Option<NumericRepr> {
+		Some(match self {
+			TensorElementType::Bool => NumericRepr::Bool,
+			TensorElementType::Int8 => NumericRepr::Signed(8),
+			TensorElementType::Int16 => NumericRepr::Signed(16),
+			TensorElementType::Int32 => NumericRepr::Signed(32),
+			TensorElementType::Int64 => NumericRepr::Signed(64),
+			TensorElementType::Uint8 => NumericRepr::Unsigned(8),
+			TensorElementType::Uint16 => NumericRepr::Unsigned(16),
+			TensorElementType::Uint32 => NumericRepr::Unsigned(32),
+			TensorElementType::Uint64 => NumericRepr::Unsigned(64),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 => NumericRepr::Float(11, 5),
+			#[cfg(feature = "half")]
+			TensorElementType::Bfloat16 => NumericRepr::Float(8, 8),
+			TensorElementType::Float32 => NumericRepr::Float(24, 8),
+			TensorElementType::Float64 => NumericRepr::Float(53, 11),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN => NumericRepr::Float(4, 4),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E5M2 => NumericRepr::Float(3, 5),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 => NumericRepr::Complex(24, 8),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex128 => NumericRepr::Complex(53, 11),
+			TensorElementType::String => return None
+		})
+	}
+}
+
+/// The value representation of a numeric [`TensorElementType`], used by
+/// [`TensorElementType::can_losslessly_convert_to`].
+enum NumericRepr {
+	Bool,
+	Signed(u32),
+	Unsigned(u32),
+	/// `(significand bits, exponent bits)`, where the significand includes the implicit leading bit.
+	Float(u32, u32),
+	/// Like [`NumericRepr::Float`], describing each of the real and imaginary parts.
+	#[cfg(feature = "complex")]
+	Complex(u32, u32)
+}
+
+/// Formats the element type as its Rust equivalent, e.g. `f32` or `String`. Use [`TensorElementType::onnx_name`] for
+/// the canonical ONNX name of the type, e.g. `tensor(float)`.
+///
+/// ```
+/// # use ort::TensorElementType;
+/// assert_eq!(TensorElementType::Float32.to_string(), "f32");
+/// ```
 impl fmt::Display for TensorElementType {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		f.write_str(match self {
+			#[cfg(feature = "half")]
 			TensorElementType::Bfloat16 => "bf16",
 			TensorElementType::Bool => "bool",
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 => "Complex<f32>",
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex128 => "Complex<f64>",
+			#[cfg(feature = "half")]
 			TensorElementType::Float16 => "f16",
 			TensorElementType::Float32 => "f32",
 			TensorElementType::Float64 => "f64",
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN => "F8E4M3",
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E5M2 => "F8E5M2",
 			TensorElementType::Int16 => "i16",
 			TensorElementType::Int32 => "i32",
 			TensorElementType::Int64 => "i64",
@@ -81,13 +469,28 @@ impl From<TensorElementType> for ort_sys::ONNXTensorElementDataType {
 			TensorElementType::Uint32 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT32,
 			TensorElementType::Uint64 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64,
 			#[cfg(feature = "half")]
-			TensorElementType::Bfloat16 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16
+			TensorElementType::Bfloat16 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16,
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX64,
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex128 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128,
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FN,
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E5M2 => ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2
 		}
 	}
 }
-impl From<ort_sys::ONNXTensorElementDataType> for TensorElementType {
-	fn from(val: ort_sys::ONNXTensorElementDataType) -> Self {
-		match val {
+impl TryFrom<ort_sys::ONNXTensorElementDataType> for TensorElementType {
+	type Error = Error;
+
+	/// Converts an ONNX Runtime element type to a [`TensorElementType`].
+	///
+	/// Returns an error with [`ErrorCode::UnsupportedTensorElementType`] if the element type is not supported by this
+	/// build of `ort`, either because it requires a crate feature which is not enabled (e.g. `half` for `f16`
+	/// tensors), or because `ort` does not support it at all (e.g. 4-bit integers).
+	fn try_from(val: ort_sys::ONNXTensorElementDataType) -> Result<Self> {
+		Ok(match val {
 			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT => TensorElementType::Float32,
 			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT8 => TensorElementType::Uint8,
 			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_INT8 => TensorElementType::Int8,
@@ -104,15 +507,97 @@ impl From<ort_sys::ONNXTensorElementDataType> for TensorElementType {
 			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UINT64 => TensorElementType::Uint64,
 			#[cfg(feature = "half")]
 			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16 => TensorElementType::Bfloat16,
-			_ => panic!("Invalid ONNXTensorElementDataType value")
-		}
+			#[cfg(not(feature = "half"))]
+			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT16
+			| ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_BFLOAT16 => return Err(unsupported_feature(val, "half")),
+			#[cfg(feature = "complex")]
+			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX64 => TensorElementType::Complex64,
+			#[cfg(feature = "complex")]
+			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128 => TensorElementType::Complex128,
+			#[cfg(not(feature = "complex"))]
+			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX64
+			| ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_COMPLEX128 => return Err(unsupported_feature(val, "complex")),
+			#[cfg(feature = "fp8")]
+			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FN => TensorElementType::Float8E4M3FN,
+			#[cfg(feature = "fp8")]
+			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2 => TensorElementType::Float8E5M2,
+			#[cfg(not(feature = "fp8"))]
+			ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E4M3FN
+			| ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT8E5M2 => return Err(unsupported_feature(val, "fp8")),
+			_ => return Err(Error::new_with_code(ErrorCode::UnsupportedTensorElementType, format!("Tensor element type {val:?} is not supported by `ort`")))
+		})
 	}
 }
 
+#[cfg(not(all(feature = "half", feature = "complex", feature = "fp8")))]
+fn unsupported_feature(ty: ort_sys::ONNXTensorElementDataType, feature: &str) -> Error {
+	Error::new_with_code(ErrorCode::UnsupportedTensorElementType, format!("Tensor element type {ty:?} requires the `{feature}` feature of `ort` to be enabled"))
+}
+
+/// Every element type supported by this build of `ort`.
+pub(crate) const SUPPORTED_ELEMENT_TYPES: &[TensorElementType] = &[
+	TensorElementType::Float32,
+	TensorElementType::Uint8,
+	TensorElementType::Int8,
+	TensorElementType::Uint16,
+	TensorElementType::Int16,
+	TensorElementType::Int32,
+	TensorElementType::Int64,
+	TensorElementType::String,
+	TensorElementType::Bool,
+	#[cfg(feature = "half")]
+	TensorElementType::Float16,
+	TensorElementType::Float64,
+	TensorElementType::Uint32,
+	TensorElementType::Uint64,
+	#[cfg(feature = "half")]
+	TensorElementType::Bfloat16,
+	#[cfg(feature = "complex")]
+	TensorElementType::Complex64,
+	#[cfg(feature = "complex")]
+	TensorElementType::Complex128,
+	#[cfg(feature = "fp8")]
+	TensorElementType::Float8E4M3FN,
+	#[cfg(feature = "fp8")]
+	TensorElementType::Float8E5M2
+];
+
+/// Returns every tensor element type that this build of `ort` can create tensors of and extract tensors to.
+///
+/// Some element types are gated behind crate features (`f16` and `bf16` require `half`, complex numbers require
+/// `complex`, and the 8-bit float types `Float8E4M3FN` & `Float8E5M2` require `fp8`), so this can be used to check at
+/// runtime whether a model's inputs & outputs are supported.
+///
+/// ```
+/// # use ort::TensorElementType;
+/// let supported = ort::supported_element_types();
+/// assert!(supported.contains(&TensorElementType::Float32));
+/// assert!(supported.contains(&TensorElementType::String));
+/// ```
+pub fn supported_element_types() -> Vec<TensorElementType> {
+	SUPPORTED_ELEMENT_TYPES.to_vec()
+}
+
 /// Trait used to map Rust types (for example `f32`) to ONNX tensor element data types (for example `Float`).
 pub trait IntoTensorElementType {
+	/// The ONNX tensor element data type corresponding to the given Rust type.
+	///
+	/// Unlike [`IntoTensorElementType::into_tensor_element_type`], this can be used in const contexts:
+	/// ```
+	/// # use ort::{IntoTensorElementType, TensorElementType};
+	/// const fn is_64_bit<T: IntoTensorElementType>() -> bool {
+	/// 	matches!(T::ELEMENT_TYPE, TensorElementType::Int64 | TensorElementType::Uint64 | TensorElementType::Float64)
+	/// }
+	///
+	/// const _: () = assert!(is_64_bit::<f64>());
+	/// const _: () = assert!(!is_64_bit::<f32>());
+	/// ```
+	const ELEMENT_TYPE: TensorElementType;
+
 	/// Returns the ONNX tensor element data type corresponding to the given Rust type.
-	fn into_tensor_element_type() -> TensorElementType;
+	fn into_tensor_element_type() -> TensorElementType {
+		Self::ELEMENT_TYPE
+	}
 
 	crate::private_trait!();
 }
@@ -124,9 +609,7 @@ pub trait PrimitiveTensorElementType: IntoTensorElementType {
 macro_rules! impl_type_trait {
 	($type_:ty, $variant:ident) => {
 		impl IntoTensorElementType for $type_ {
-			fn into_tensor_element_type() -> TensorElementType {
-				TensorElementType::$variant
-			}
+			const ELEMENT_TYPE: TensorElementType = TensorElementType::$variant;
 
 			crate::private_impl!();
 		}
@@ -144,6 +627,8 @@ impl_type_trait!(u16, Uint16);
 impl_type_trait!(i16, Int16);
 impl_type_trait!(i32, Int32);
 impl_type_trait!(i64, Int64);
+// ONNX Runtime stores booleans as one byte per element, which is always 0 or 1 for tensors produced by its kernels,
+// so its data can be reinterpreted as Rust `bool`s
 impl_type_trait!(bool, Bool);
 #[cfg(feature = "half")]
 #[cfg_attr(docsrs, doc(cfg(feature = "half")))]
@@ -154,11 +639,109 @@ impl_type_trait!(u64, Uint64);
 #[cfg(feature = "half")]
 #[cfg_attr(docsrs, doc(cfg(feature = "half")))]
 impl_type_trait!(half::bf16, Bfloat16);
+// `num_complex::Complex` is `#[repr(C)]`, matching the layout of ONNX Runtime's complex types
+#[cfg(feature = "complex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "complex")))]
+impl_type_trait!(num_complex::Complex<f32>, Complex64);
+#[cfg(feature = "complex")]
+#[cfg_attr(docsrs, doc(cfg(feature = "complex")))]
+impl_type_trait!(num_complex::Complex<f64>, Complex128);
+#[cfg(feature = "fp8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fp8")))]
+impl_type_trait!(float8::F8E4M3, Float8E4M3FN);
+#[cfg(feature = "fp8")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fp8")))]
+impl_type_trait!(float8::F8E5M2, Float8E5M2);
+
+/// A numeric tensor element type, which values of other numeric types can be cast to with
+/// [`Tensor::try_extract_as`](crate::Tensor::try_extract_as).
+///
+/// Casts only succeed if the value can be represented in this type: narrowing integer conversions (like `i64` to
+/// `i32`) fail on overflow, and float-to-integer conversions fail if the value is not a whole number in range.
+/// Conversions to floating point types are always allowed, but may lose precision, and fail if a finite value would
+/// overflow to infinity.
+pub trait NumericTensorElementType: PrimitiveTensorElementType + Copy {
+	#[doc(hidden)]
+	fn cast_from_i64(value: i64) -> Option<Self>;
+	#[doc(hidden)]
+	fn cast_from_u64(value: u64) -> Option<Self>;
+	#[doc(hidden)]
+	fn cast_from_f64(value: f64) -> Option<Self>;
+	#[doc(hidden)]
+	fn cast_into<T: NumericTensorElementType>(self) -> Option<T>;
+
+	crate::private_trait!();
+}
+
+macro_rules! impl_numeric_int {
+	($($type_:ty),+) => {
+		$(impl NumericTensorElementType for $type_ {
+			fn cast_from_i64(value: i64) -> Option<Self> {
+				<$type_>::try_from(value).ok()
+			}
+
+			fn cast_from_u64(value: u64) -> Option<Self> {
+				<$type_>::try_from(value).ok()
+			}
+
+			fn cast_from_f64(value: f64) -> Option<Self> {
+				// `as i128` is exact for any whole number in range of a 64-bit integer
+				if value.is_finite() && value.fract() == 0.0 { <$type_>::try_from(value as i128).ok() } else { None }
+			}
+
+			fn cast_into<T: NumericTensorElementType>(self) -> Option<T> {
+				// only unsigned values above `i64::MAX` need to go through `u64`
+				match i64::try_from(self) {
+					Ok(value) => T::cast_from_i64(value),
+					Err(_) => T::cast_from_u64(self as u64)
+				}
+			}
+
+			crate::private_impl!();
+		})+
+	};
+}
+
+macro_rules! impl_numeric_float {
+	($($type_:ty: $from_f64:expr, $to_f64:expr, $is_finite:expr),+) => {
+		$(impl NumericTensorElementType for $type_ {
+			fn cast_from_i64(value: i64) -> Option<Self> {
+				Self::cast_from_f64(value as f64)
+			}
+
+			fn cast_from_u64(value: u64) -> Option<Self> {
+				Self::cast_from_f64(value as f64)
+			}
+
+			fn cast_from_f64(value: f64) -> Option<Self> {
+				let cast: $type_ = $from_f64(value);
+				if value.is_finite() && !$is_finite(cast) { None } else { Some(cast) }
+			}
+
+			fn cast_into<T: NumericTensorElementType>(self) -> Option<T> {
+				T::cast_from_f64($to_f64(self))
+			}
+
+			crate::private_impl!();
+		})+
+	};
+}
+
+impl_numeric_int!(u8, i8, u16, i16, u32, i32, u64, i64);
+impl_numeric_float!(f32: |v| v as f32, f64::from, f32::is_finite, f64: |v| v, |v| v, f64::is_finite);
+#[cfg(feature = "half")]
+impl_numeric_float!(
+	half::f16: half::f16::from_f64, half::f16::to_f64, half::f16::is_finite,
+	half::bf16: half::bf16::from_f64, half::bf16::to_f64, half::bf16::is_finite
+);
+#[cfg(feature = "fp8")]
+impl_numeric_float!(
+	float8::F8E4M3: float8::F8E4M3::from_f64, |v: float8::F8E4M3| v.to_f64(), |v: float8::F8E4M3| v.is_finite(),
+	float8::F8E5M2: float8::F8E5M2::from_f64, |v: float8::F8E5M2| v.to_f64(), |v: float8::F8E5M2| v.is_finite()
+);
 
 impl IntoTensorElementType for String {
-	fn into_tensor_element_type() -> TensorElementType {
-		TensorElementType::String
-	}
+	const ELEMENT_TYPE: TensorElementType = TensorElementType::String;
 
 	crate::private_impl!();
 }
@@ -181,6 +764,24 @@ impl<'a> Utf8Data for &'a str {
 	}
 }
 
+impl<'a> Utf8Data for Cow<'a, str> {
+	fn as_utf8_bytes(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
+impl Utf8Data for Box<str> {
+	fn as_utf8_bytes(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
+impl Utf8Data for Arc<str> {
+	fn as_utf8_bytes(&self) -> &[u8] {
+		self.as_bytes()
+	}
+}
+
 /// Construct an [`ndarray::ArrayView`] for an ORT tensor.
 ///
 /// Only to be used on types whose Rust in-memory representation matches ONNX Runtime's (e.g. primitive numeric types
@@ -4,7 +4,7 @@ use std::{
 	hash::Hash,
 	marker::PhantomData,
 	ptr::{self, NonNull},
-	sync::Arc
+	sync::{Arc, OnceLock}
 };
 
 use super::{
@@ -77,8 +77,30 @@ pub type MapRef<'v, K, V> = ValueRef<'v, MapValueType<K, V>>;
 pub type MapRefMut<'v, K, V> = ValueRefMut<'v, MapValueType<K, V>>;
 
 impl<Type: MapValueTypeMarker + ?Sized> Value<Type> {
+	/// Attempt to extract the map's keys and values into a [`HashMap`] with keys of type `K` and values of type `V`.
+	///
+	/// ```
+	/// # use ort::Map;
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Map::<String, f32>::new([("cat".to_string(), 0.9), ("dog".to_string(), 0.1)])?.upcast();
+	///
+	/// let probabilities = value.try_extract_map::<String, f32>()?;
+	/// assert_eq!(probabilities["cat"], 0.9);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// Classifiers converted from scikit-learn typically output their class probabilities through a `ZipMap`
+	/// operator, producing a [`crate::Sequence`] of maps (one per input row) with `i64` or `String` keys and `f32`
+	/// values. Each map of the sequence (extracted via [`crate::Sequence::try_extract_sequence`]) can then be
+	/// extracted with this method.
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - `K` or `V` do not match the map's key or value type.
+	/// - The map's data is not allocated in CPU memory.
 	pub fn try_extract_map<K: IntoTensorElementType + Clone + Hash + Eq, V: PrimitiveTensorElementType + Clone>(&self) -> Result<HashMap<K, V>> {
-		match self.dtype() {
+		match self.try_dtype()? {
 			ValueType::Map { key, value } => {
 				let k_type = K::into_tensor_element_type();
 				if k_type != key {
@@ -98,7 +120,7 @@ impl<Type: MapValueTypeMarker + ?Sized> Value<Type> {
 				ortsys![unsafe GetValue(self.ptr(), 0, allocator.ptr.as_ptr(), &mut key_tensor_ptr)?; nonNull(key_tensor_ptr)];
 				let key_value: DynTensor = unsafe { Value::from_ptr(NonNull::new_unchecked(key_tensor_ptr), None) };
 				if K::into_tensor_element_type() != TensorElementType::String {
-					let dtype = key_value.dtype();
+					let dtype = key_value.try_dtype()?;
 					let (key_tensor_shape, key_tensor) = match dtype {
 						ValueType::Tensor { ty, dimensions } => {
 							let mem = key_value.memory_info();
@@ -254,7 +276,8 @@ impl<K: IntoTensorElementType + Debug + Clone + Hash + Eq + 'static, V: IntoTens
 			inner: Arc::new(ValueInner::RustOwned {
 				ptr: unsafe { NonNull::new_unchecked(value_ptr) },
 				_array: Box::new(values),
-				_memory_info: None
+				_memory_info: None,
+				dims: OnceLock::new()
 			}),
 			_markers: PhantomData
 		})
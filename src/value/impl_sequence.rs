@@ -2,7 +2,7 @@ use std::{
 	fmt::Debug,
 	marker::PhantomData,
 	ptr::{self, NonNull},
-	sync::Arc
+	sync::{Arc, OnceLock}
 };
 
 use super::{DowncastableTarget, Value, ValueInner, ValueRef, ValueRefMut, ValueType, ValueTypeMarker};
@@ -56,7 +56,7 @@ impl<Type: SequenceValueTypeMarker + Sized> Value<Type> {
 		&self,
 		allocator: &Allocator
 	) -> Result<Vec<Value<OtherType>>> {
-		match self.dtype() {
+		match self.try_dtype()? {
 			ValueType::Sequence(_) => {
 				let mut len: ort_sys::size_t = 0;
 				ortsys![unsafe GetValueCount(self.ptr(), &mut len)?];
@@ -67,8 +67,8 @@ impl<Type: SequenceValueTypeMarker + Sized> Value<Type> {
 					ortsys![unsafe GetValue(self.ptr(), i as _, allocator.ptr.as_ptr(), &mut value_ptr)?; nonNull(value_ptr)];
 
 					let value = unsafe { Value::from_ptr(NonNull::new_unchecked(value_ptr), None) };
-					let value_type = value.dtype();
-					if !OtherType::can_downcast(&value.dtype()) {
+					let value_type = value.try_dtype()?;
+					if !OtherType::can_downcast(&value_type) {
 						return Err(Error::new_with_code(
 							ErrorCode::InvalidArgument,
 							format!("Cannot extract Sequence<{}> from {value_type:?}", OtherType::format())
@@ -115,7 +115,8 @@ impl<T: ValueTypeMarker + DowncastableTarget + Debug + Sized + 'static> Value<Se
 			inner: Arc::new(ValueInner::RustOwned {
 				ptr: unsafe { NonNull::new_unchecked(value_ptr) },
 				_array: Box::new(values),
-				_memory_info: None
+				_memory_info: None,
+				dims: OnceLock::new()
 			}),
 			_markers: PhantomData
 		})
@@ -0,0 +1,118 @@
+use candle_core::{DType, Device};
+
+use super::{DynTensor, Tensor, TensorValueTypeMarker};
+use crate::{
+	error::{Error, ErrorCode, Result},
+	tensor::TensorElementType,
+	value::{Value, ValueType}
+};
+
+impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
+	/// Attempt to extract the underlying data into a [`candle_core::Tensor`] on the given device, copying it.
+	///
+	/// The Candle tensor's dtype is mapped from the tensor's element type, e.g. `Tensor<f32>` becomes a tensor of
+	/// `DType::F32`. Candle only supports a subset of ONNX's element types (`u8`, `u32`, `i64`, `f16`, `bf16`, `f32`,
+	/// and `f64`); extracting other types will fail.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_array(([2usize, 2], vec![1.0_f32, 2.0, 3.0, 4.0].into_boxed_slice()))?;
+	///
+	/// let candle_tensor = tensor.try_extract_candle(&candle_core::Device::Cpu)?;
+	/// assert_eq!(candle_tensor.dims(), &[2, 2]);
+	/// assert_eq!(candle_tensor.dtype(), candle_core::DType::F32);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor's element type has no Candle equivalent (e.g. `i32` or `String`).
+	/// - The tensor's data is not allocated in CPU memory.
+	/// - Candle fails to allocate the tensor on `device`.
+	#[cfg_attr(docsrs, doc(cfg(feature = "candle")))]
+	pub fn try_extract_candle(&self, device: &Device) -> Result<candle_core::Tensor> {
+		let ty = match self.try_dtype()? {
+			ValueType::Tensor { ty, .. } => ty,
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract a Candle tensor from {t}")))
+		};
+
+		macro_rules! candle_tensor {
+			($t:ty) => {{
+				let (shape, data) = self.try_extract_raw_tensor::<$t>()?;
+				candle_core::Tensor::from_slice(data, shape.into_iter().map(|d| d as usize).collect::<Vec<_>>(), device).map_err(Error::wrap)
+			}};
+		}
+
+		match ty {
+			TensorElementType::Uint8 => candle_tensor!(u8),
+			TensorElementType::Uint32 => candle_tensor!(u32),
+			TensorElementType::Int64 => candle_tensor!(i64),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 => candle_tensor!(half::f16),
+			#[cfg(feature = "half")]
+			TensorElementType::Bfloat16 => candle_tensor!(half::bf16),
+			TensorElementType::Float32 => candle_tensor!(f32),
+			TensorElementType::Float64 => candle_tensor!(f64),
+			ty => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Tensor<{ty}> cannot be converted to a Candle tensor")))
+		}
+	}
+}
+
+impl DynTensor {
+	/// Construct a tensor from a [`candle_core::Tensor`], copying its data.
+	///
+	/// The tensor's element type is mapped from the Candle tensor's dtype, e.g. a Candle tensor of `DType::F32` becomes
+	/// a `Tensor<f32>`. The Candle tensor must reside on the CPU; tensors on other devices should first be moved with
+	/// `candle_core::Tensor::to_device`.
+	///
+	/// ```
+	/// # use ort::DynTensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let candle_tensor = candle_core::Tensor::from_slice(&[1_i64, 2, 3, 4], (2, 2), &candle_core::Device::Cpu).unwrap();
+	///
+	/// let tensor = DynTensor::from_candle(&candle_tensor)?;
+	/// assert_eq!(tensor.try_extract_raw_tensor::<i64>()?, (vec![2, 2], &[1, 2, 3, 4][..]));
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - The Candle tensor does not reside on the CPU.
+	/// - The Candle tensor's dtype requires the `half` feature, which is not enabled.
+	/// - The Candle tensor has a dimension of size 0.
+	#[cfg_attr(docsrs, doc(cfg(feature = "candle")))]
+	pub fn from_candle(tensor: &candle_core::Tensor) -> Result<DynTensor> {
+		if !tensor.device().is_cpu() {
+			return Err(Error::new(format!("Cannot create a tensor from a Candle tensor on device {:?}, which is not the CPU", tensor.device().location())));
+		}
+
+		let shape = tensor.dims().to_vec();
+		macro_rules! tensor {
+			($t:ty) => {{
+				let data = tensor.flatten_all().and_then(|t| t.to_vec1::<$t>()).map_err(Error::wrap)?;
+				Tensor::from_array((shape, data)).map(|c| c.upcast())
+			}};
+		}
+
+		match tensor.dtype() {
+			DType::U8 => tensor!(u8),
+			DType::U32 => tensor!(u32),
+			DType::I64 => tensor!(i64),
+			#[cfg(feature = "half")]
+			DType::F16 => tensor!(half::f16),
+			#[cfg(feature = "half")]
+			DType::BF16 => tensor!(half::bf16),
+			#[cfg(not(feature = "half"))]
+			dtype @ (DType::F16 | DType::BF16) => Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Cannot create a tensor from a Candle tensor of dtype {dtype:?} without the `half` feature enabled")
+			)),
+			DType::F32 => tensor!(f32),
+			DType::F64 => tensor!(f64)
+		}
+	}
+}
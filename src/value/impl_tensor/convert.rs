@@ -0,0 +1,235 @@
+use std::{collections::VecDeque, fmt::Debug, sync::Arc};
+
+#[cfg(feature = "ndarray")]
+use ndarray::{Array, ArrayBase, ArrayView, Data, Dimension};
+
+use super::{Tensor, TensorValueTypeMarker, create::ToDimensions, dynamic::TensorData};
+#[cfg(feature = "ndarray")]
+use crate::error::{Error, ErrorCode};
+use crate::{
+	error::Result,
+	tensor::PrimitiveTensorElementType,
+	value::{DynValue, Value}
+};
+
+/// A type which can be converted to a [`DynValue`], e.g. to be used as a session input.
+///
+/// `ToValue` is implemented for scalars, `ndarray` arrays, and `(shape, data)` tuples of primitive or string elements -
+/// i.e. anything a [`Tensor`] can be created from. Since it takes `&self`, the data is always copied into the new
+/// value.
+///
+/// Implement `ToValue` for your own types to give them a single, reusable conversion to a model input:
+///
+/// ```
+/// # use ort::{DynValue, ToValue};
+/// struct Pixel {
+/// 	r: u8,
+/// 	g: u8,
+/// 	b: u8
+/// }
+///
+/// impl ToValue for Pixel {
+/// 	fn to_value(&self) -> ort::Result<DynValue> {
+/// 		([1usize, 3], vec![self.r, self.g, self.b]).to_value()
+/// 	}
+/// }
+///
+/// # fn main() -> ort::Result<()> {
+/// let value = Pixel { r: 255, g: 128, b: 0 }.to_value()?;
+/// assert_eq!(value.try_extract_raw_tensor::<u8>()?.1, &[255, 128, 0]);
+///
+/// let scalar = 3.5_f32.to_value()?;
+/// assert_eq!(scalar.try_extract_scalar::<f32>()?, 3.5);
+/// # 	Ok(())
+/// # }
+/// ```
+pub trait ToValue {
+	/// Creates a new [`DynValue`] containing a copy of this data.
+	fn to_value(&self) -> Result<DynValue>;
+}
+
+impl<T: PrimitiveTensorElementType + Debug + Clone + 'static> ToValue for T {
+	fn to_value(&self) -> Result<DynValue> {
+		Tensor::from_array(((), vec![self.clone()])).map(Tensor::into_dyn)
+	}
+}
+
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+impl<T: PrimitiveTensorElementType + Debug + Clone + 'static, S: Data<Elem = T>, D: Dimension + 'static> ToValue for ArrayBase<S, D> {
+	fn to_value(&self) -> Result<DynValue> {
+		Tensor::from_array(self.view()).map(Tensor::into_dyn)
+	}
+}
+
+macro_rules! impl_to_value {
+	($($t:ty),+) => {
+		$(
+			impl<T: PrimitiveTensorElementType + Debug + Clone + 'static, I: ToDimensions> ToValue for (I, $t) {
+				fn to_value(&self) -> Result<DynValue> {
+					let shape = self.0.to_dimensions(Some(self.1.len()))?;
+					Tensor::from_array((shape, &self.1[..])).map(Tensor::into_dyn)
+				}
+			}
+		)+
+	};
+	(@string $($t:ty),+) => {
+		$(
+			impl<I: ToDimensions> ToValue for (I, $t) {
+				fn to_value(&self) -> Result<DynValue> {
+					let shape = self.0.to_dimensions(Some(self.1.len()))?;
+					Tensor::from_string_array((shape, &self.1[..])).map(Tensor::into_dyn)
+				}
+			}
+		)+
+	};
+}
+
+impl_to_value!(Vec<T>, Box<[T]>, Arc<Box<[T]>>, &[T]);
+impl_to_value!(@string Vec<String>, Box<[String]>, &[String]);
+
+/// A type which can be extracted from a tensor [`Value`], e.g. a session output; the counterpart to [`ToValue`].
+///
+/// `FromValue` is implemented for scalars, `ndarray` arrays & views, `Vec<String>`, and [`TensorData`], so generic code
+/// can extract outputs directly into the Rust types it works with. The lifetime `'v` is that of the borrowed value,
+/// which allows zero-copy implementations like the one for [`ndarray::ArrayView`].
+///
+/// ```
+/// # use ort::{FromValue, Tensor};
+/// # fn main() -> ort::Result<()> {
+/// let value = Tensor::from_array(([2usize, 2], vec![1.0_f32, 2.0, 3.0, 4.0]))?;
+///
+/// let array: ndarray::Array2<f32> = FromValue::from_value(&value)?;
+/// assert_eq!(array, ndarray::arr2(&[[1.0, 2.0], [3.0, 4.0]]));
+///
+/// // extracting into a type of the wrong dimensionality fails
+/// assert!(ndarray::Array1::<f32>::from_value(&value).is_err());
+/// # 	Ok(())
+/// # }
+/// ```
+pub trait FromValue<'v>: Sized {
+	/// Extracts the data of `value` as `Self`.
+	fn from_value<Type: TensorValueTypeMarker + ?Sized>(value: &'v Value<Type>) -> Result<Self>;
+}
+
+impl<'v, T: PrimitiveTensorElementType + Copy> FromValue<'v> for T {
+	fn from_value<Type: TensorValueTypeMarker + ?Sized>(value: &'v Value<Type>) -> Result<Self> {
+		value.try_extract_scalar()
+	}
+}
+
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+impl<'v, T: PrimitiveTensorElementType, D: Dimension> FromValue<'v> for ArrayView<'v, T, D> {
+	fn from_value<Type: TensorValueTypeMarker + ?Sized>(value: &'v Value<Type>) -> Result<Self> {
+		let view = value.try_extract_tensor::<T>()?;
+		let shape = view.shape().to_vec();
+		view.into_dimensionality::<D>()
+			.map_err(|e| Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract an array from a tensor of shape {shape:?}: {e}")))
+	}
+}
+
+#[cfg(feature = "ndarray")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+impl<'v, T: PrimitiveTensorElementType + Clone, D: Dimension> FromValue<'v> for Array<T, D> {
+	fn from_value<Type: TensorValueTypeMarker + ?Sized>(value: &'v Value<Type>) -> Result<Self> {
+		ArrayView::<T, D>::from_value(value).map(|view| view.to_owned())
+	}
+}
+
+impl<'v> FromValue<'v> for Vec<String> {
+	fn from_value<Type: TensorValueTypeMarker + ?Sized>(value: &'v Value<Type>) -> Result<Self> {
+		value.try_extract_raw_string_tensor().map(|(_, data)| data)
+	}
+}
+
+impl<'v> FromValue<'v> for TensorData<'v> {
+	fn from_value<Type: TensorValueTypeMarker + ?Sized>(value: &'v Value<Type>) -> Result<Self> {
+		value.try_extract_dynamic()
+	}
+}
+
+/// A container which the data of a tensor [`Value`] can be appended to, allowing extraction into existing buffers
+/// (e.g. to reuse their allocation across runs) rather than allocating a new one for each output.
+///
+/// `ExtractInto` is implemented for [`Vec`] and [`VecDeque`] of primitive elements & `String`s. Data is appended in
+/// row-major order, and the tensor's shape is discarded.
+///
+/// Implement `ExtractInto` for your own containers to allow extracting into them:
+///
+/// ```
+/// # use ort::{ExtractInto, Tensor, TensorValueTypeMarker, Value};
+/// /// A buffer that only keeps the last `N` elements written to it.
+/// struct Last<const N: usize>(Vec<f32>);
+///
+/// impl<const N: usize> ExtractInto for Last<N> {
+/// 	fn extend_from_tensor<Type: TensorValueTypeMarker + ?Sized>(&mut self, value: &Value<Type>) -> ort::Result<()> {
+/// 		self.0.extend_from_tensor(value)?;
+/// 		let excess = self.0.len().saturating_sub(N);
+/// 		self.0.drain(..excess);
+/// 		Ok(())
+/// 	}
+/// }
+///
+/// # fn main() -> ort::Result<()> {
+/// let mut buffer: Vec<f32> = Vec::with_capacity(8);
+/// Tensor::from_array(([2usize, 2], vec![1.0_f32, 2.0, 3.0, 4.0]))?.extend_into(&mut buffer)?;
+/// assert_eq!(buffer, [1.0, 2.0, 3.0, 4.0]);
+///
+/// let mut last = Last::<3>(Vec::new());
+/// Tensor::from_array(([2usize, 2], vec![1.0_f32, 2.0, 3.0, 4.0]))?.extend_into(&mut last)?;
+/// assert_eq!(last.0, [2.0, 3.0, 4.0]);
+/// # 	Ok(())
+/// # }
+/// ```
+pub trait ExtractInto {
+	/// Appends the data of `value` to this container.
+	///
+	/// # Errors
+	/// May return an error if `value` is not a tensor, its element type does not match the container's, or its data is
+	/// not allocated in CPU memory. The container is left unchanged if an error is returned.
+	fn extend_from_tensor<Type: TensorValueTypeMarker + ?Sized>(&mut self, value: &Value<Type>) -> Result<()>;
+}
+
+impl<T: PrimitiveTensorElementType + Clone> ExtractInto for Vec<T> {
+	fn extend_from_tensor<Type: TensorValueTypeMarker + ?Sized>(&mut self, value: &Value<Type>) -> Result<()> {
+		let (_, data) = value.try_extract_raw_tensor::<T>()?;
+		self.extend_from_slice(data);
+		Ok(())
+	}
+}
+
+impl<T: PrimitiveTensorElementType + Clone> ExtractInto for VecDeque<T> {
+	fn extend_from_tensor<Type: TensorValueTypeMarker + ?Sized>(&mut self, value: &Value<Type>) -> Result<()> {
+		let (_, data) = value.try_extract_raw_tensor::<T>()?;
+		self.extend(data.iter().cloned());
+		Ok(())
+	}
+}
+
+impl ExtractInto for Vec<String> {
+	fn extend_from_tensor<Type: TensorValueTypeMarker + ?Sized>(&mut self, value: &Value<Type>) -> Result<()> {
+		let (_, data) = value.try_extract_raw_string_tensor()?;
+		self.extend(data);
+		Ok(())
+	}
+}
+
+impl ExtractInto for VecDeque<String> {
+	fn extend_from_tensor<Type: TensorValueTypeMarker + ?Sized>(&mut self, value: &Value<Type>) -> Result<()> {
+		let (_, data) = value.try_extract_raw_string_tensor()?;
+		self.extend(data);
+		Ok(())
+	}
+}
+
+impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
+	/// Appends the data of this tensor to `out`; see [`ExtractInto`].
+	///
+	/// # Errors
+	/// May return an error if this is not a tensor, its element type does not match `out`'s, or its data is not
+	/// allocated in CPU memory.
+	pub fn extend_into<C: ExtractInto + ?Sized>(&self, out: &mut C) -> Result<()> {
+		out.extend_from_tensor(self)
+	}
+}
@@ -1,21 +1,22 @@
 use std::{
 	any::Any,
+	collections::HashMap,
 	ffi,
 	fmt::Debug,
 	marker::PhantomData,
 	ptr::{self, NonNull},
-	sync::Arc
+	sync::{Arc, OnceLock}
 };
 
 #[cfg(feature = "ndarray")]
-use ndarray::{ArcArray, Array, ArrayView, CowArray, Dimension};
+use ndarray::{ArcArray, Array, ArrayBase, ArrayView, CowArray, Data, Dimension, IxDyn};
 
-use super::{DynTensor, Tensor, TensorRefMut, calculate_tensor_size};
+use super::{DynTensor, Tensor, TensorRef, TensorRefMut, calculate_tensor_size};
 use crate::{
 	error::{Error, ErrorCode, Result, assert_non_null_pointer},
 	memory::{AllocationDevice, Allocator, AllocatorType, MemoryInfo, MemoryType},
 	ortsys,
-	tensor::{PrimitiveTensorElementType, TensorElementType, Utf8Data},
+	tensor::{NumericTensorElementType, PrimitiveTensorElementType, TensorElementType, Utf8Data},
 	value::{DynValue, Value, ValueInner}
 };
 
@@ -78,7 +79,8 @@ impl Tensor<String> {
 			inner: Arc::new(ValueInner::RustOwned {
 				ptr: unsafe { NonNull::new_unchecked(value_ptr) },
 				_array: Box::new(()),
-				_memory_info: None
+				_memory_info: None,
+				dims: OnceLock::new()
 			}),
 			_markers: PhantomData
 		})
@@ -126,12 +128,49 @@ impl<T: PrimitiveTensorElementType + Debug> Tensor<T> {
 			inner: Arc::new(ValueInner::RustOwned {
 				ptr: unsafe { NonNull::new_unchecked(value_ptr) },
 				_array: Box::new(()),
-				_memory_info: None
+				_memory_info: None,
+				dims: OnceLock::new()
 			}),
 			_markers: PhantomData
 		})
 	}
 
+	/// Construct a tensor of the given shape from an iterator with a known length, writing its elements directly into
+	/// the tensor's memory, in row-major order, without collecting them into an intermediate `Vec`.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_iter_exact([2, 3], (0..6).map(|x| x as f32 * 0.5))?;
+	/// assert_eq!(tensor.extract_raw_tensor().1, &[0.0, 0.5, 1.0, 1.5, 2.0, 2.5]);
+	///
+	/// // the length of the iterator must match the shape
+	/// assert!(Tensor::from_iter_exact([2, 3], (0..5).map(|x| x as f32)).is_err());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// Returns an error if any dimension of `shape` is less than 1, or if the number of elements described by `shape`
+	/// does not match the length of the iterator. The length is validated before the tensor is allocated.
+	pub fn from_iter_exact(shape: impl ToDimensions, iter: impl ExactSizeIterator<Item = T>) -> Result<Tensor<T>> {
+		let len = iter.len();
+		let shape = shape.to_dimensions(Some(len))?;
+
+		let mut tensor = Tensor::new(&Allocator::default(), shape)?;
+		let (_, data) = tensor.extract_raw_tensor_mut();
+		let mut written = 0;
+		for (slot, x) in data.iter_mut().zip(iter) {
+			*slot = x;
+			written += 1;
+		}
+		// `ExactSizeIterator::len` can't be relied upon for safety, but a wrong length is still a bug we should surface
+		if written != len {
+			return Err(Error::new(format!("Iterator reported a length of {len}, but only yielded {written} elements")));
+		}
+		Ok(tensor)
+	}
+
 	/// Construct a tensor from an array of data.
 	///
 	/// Tensors can be created from:
@@ -163,10 +202,22 @@ impl<T: PrimitiveTensorElementType + Debug> Tensor<T> {
 	/// - `&CowArray<'_, T, D>` will always be copied regardless of whether it is uniquely owned or borrowed.
 	/// - `&mut ArcArray<T, D>` and `Array<T, D>` will be copied only if the data is not in a contiguous layout (which
 	///   is the case after most reshape operations)
-	/// - `ArrayView<'_, T, D>` will always be copied.
+	/// - `ArrayView<'_, T, D>` will always be copied; use [`Tensor::from_array_view`] to borrow its data instead.
 	///
 	/// Raw data provided as a `Arc<Box<[T]>>`, `Box<[T]>`, or `Vec<T>` will never be copied. Raw data is expected to be
-	/// in standard, contigous layout.
+	/// in standard, contigous layout. Ownership of the data is transferred to the tensor, and it is freed when the
+	/// tensor is dropped:
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let data = vec![1.0_f32; 1024];
+	/// let data_ptr = data.as_ptr();
+	///
+	/// let tensor = Tensor::from_array(([32usize, 32], data))?;
+	/// assert_eq!(tensor.data_ptr()?.cast::<f32>(), data_ptr);
+	/// # 	Ok(())
+	/// # }
+	/// ```
 	pub fn from_array(input: impl IntoValueTensor<Item = T>) -> Result<Tensor<T>> {
 		let memory_info = MemoryInfo::new(AllocationDevice::CPU, 0, AllocatorType::Arena, MemoryType::CPUInput)?;
 
@@ -197,11 +248,509 @@ impl<T: PrimitiveTensorElementType + Debug> Tensor<T> {
 			inner: Arc::new(ValueInner::RustOwned {
 				ptr: unsafe { NonNull::new_unchecked(value_ptr) },
 				_array: guard,
-				_memory_info: Some(memory_info)
+				_memory_info: Some(memory_info),
+				dims: OnceLock::new()
 			}),
 			_markers: PhantomData
 		})
 	}
+
+	/// Construct a tensor view from an [`ndarray::ArrayView`], borrowing its data if it is in a contiguous, standard
+	/// layout, or copying it into a contiguous array otherwise (e.g. if the view is a non-contiguous slice of a
+	/// larger array).
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # use ndarray::{Array2, s};
+	/// # fn main() -> ort::Result<()> {
+	/// let array = Array2::<f32>::from_shape_fn((4, 4), |(y, x)| (y * 4 + x) as f32);
+	///
+	/// // contiguous views are borrowed...
+	/// let rows = array.slice(s![1..3, ..]);
+	/// let tensor = Tensor::from_array_view(rows)?;
+	/// assert_eq!(tensor.data_ptr()?.cast::<f32>(), rows.as_ptr());
+	///
+	/// // ...while non-contiguous views are copied
+	/// let columns = array.slice(s![.., 1..3]);
+	/// let tensor = Tensor::from_array_view(columns)?;
+	/// assert_eq!(tensor.extract_raw_tensor().1, &[1.0, 2.0, 5.0, 6.0, 9.0, 10.0, 13.0, 14.0]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn from_array_view<'v, D: Dimension + 'static>(view: ArrayView<'v, T, D>) -> Result<TensorRef<'v, T>>
+	where
+		T: Clone + 'static
+	{
+		if !view.is_standard_layout() {
+			return Tensor::from_array(view.as_standard_layout().into_owned()).map(TensorRef::new);
+		}
+
+		let memory_info = MemoryInfo::new(AllocationDevice::CPU, 0, AllocatorType::Arena, MemoryType::CPUInput)?;
+
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+
+		let shape: Vec<i64> = view.shape().iter().map(|d| *d as i64).collect();
+		let shape_ptr: *const i64 = shape.as_ptr();
+		let shape_len = shape.len();
+
+		// ONNX Runtime takes a mutable pointer, but `TensorRef` only provides immutable access to the data
+		let tensor_values_ptr: *mut std::ffi::c_void = view.as_ptr().cast_mut().cast();
+		assert_non_null_pointer(tensor_values_ptr, "TensorValues")?;
+
+		ortsys![
+			unsafe CreateTensorWithDataAsOrtValue(
+				memory_info.ptr.as_ptr(),
+				tensor_values_ptr,
+				(view.len() * std::mem::size_of::<T>()) as _,
+				shape_ptr,
+				shape_len as _,
+				T::into_tensor_element_type().into(),
+				&mut value_ptr
+			)?;
+			nonNull(value_ptr)
+		];
+
+		Ok(TensorRef::new(Value {
+			inner: Arc::new(ValueInner::CppOwned {
+				ptr: unsafe { NonNull::new_unchecked(value_ptr) },
+				drop: true,
+				// the data is borrowed from the view, whose lifetime is tracked by the `TensorRef`
+				borrowed: true,
+				_session: None,
+				dims: OnceLock::new()
+			}),
+			_markers: PhantomData
+		}))
+	}
+
+	/// Construct a tensor view of the given shape from a slice, borrowing its data without copying it. Unlike
+	/// [`Tensor::from_array_view`], this does not require the `ndarray` feature.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let data = [1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0];
+	/// let tensor = Tensor::from_slice([2, 3], &data)?;
+	/// assert_eq!(tensor.data_ptr()?.cast::<f32>(), data.as_ptr());
+	/// assert_eq!(tensor.extract_raw_tensor(), (vec![2, 3], &data[..]));
+	///
+	/// // the length of the slice must match the shape
+	/// assert!(Tensor::from_slice([4, 2], &data).is_err());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// The returned view cannot outlive `data`; in particular, it cannot be upgraded to an owned tensor with
+	/// [`ValueRef::try_upgrade`](crate::ValueRef::try_upgrade).
+	///
+	/// The tensor is always described as CPU memory, so this does not take a [`MemoryInfo`]: a slice is host memory,
+	/// and describing it as memory on another device would have ONNX Runtime (or the execution provider) access it as
+	/// device memory. To wrap memory that lives on another device, use the unsafe [`TensorRefMut::from_raw`].
+	///
+	/// # Errors
+	/// Returns an error if any dimension of `shape` is less than 1, or if the number of elements described by `shape`
+	/// does not match the length of `data`.
+	#[doc(alias = "from_slice_shape")]
+	pub fn from_slice<'v>(shape: impl ToDimensions, data: &'v [T]) -> Result<TensorRef<'v, T>> {
+		let shape = shape.to_dimensions(Some(data.len()))?;
+
+		let memory_info = MemoryInfo::new(AllocationDevice::CPU, 0, AllocatorType::Arena, MemoryType::CPUInput)?;
+
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+
+		// ONNX Runtime takes a mutable pointer, but `TensorRef` only provides immutable access to the data
+		let tensor_values_ptr: *mut std::ffi::c_void = data.as_ptr().cast_mut().cast();
+		assert_non_null_pointer(tensor_values_ptr, "TensorValues")?;
+
+		ortsys![
+			unsafe CreateTensorWithDataAsOrtValue(
+				memory_info.ptr.as_ptr(),
+				tensor_values_ptr,
+				std::mem::size_of_val(data) as _,
+				shape.as_ptr(),
+				shape.len() as _,
+				T::into_tensor_element_type().into(),
+				&mut value_ptr
+			)?;
+			nonNull(value_ptr)
+		];
+
+		Ok(TensorRef::new(Value {
+			inner: Arc::new(ValueInner::CppOwned {
+				ptr: unsafe { NonNull::new_unchecked(value_ptr) },
+				drop: true,
+				// the data is borrowed from the slice, whose lifetime is tracked by the `TensorRef`
+				borrowed: true,
+				_session: None,
+				dims: OnceLock::new()
+			}),
+			_markers: PhantomData
+		}))
+	}
+
+	/// Construct a tensor of shape `target_shape` by broadcasting `data` to it, following NumPy's broadcasting rules:
+	/// `data`'s dimensions are aligned with the trailing dimensions of `target_shape`, and each of them must either
+	/// match the corresponding target dimension or be 1, in which case it is repeated along that dimension.
+	///
+	/// The broadcasted data is copied into a new tensor.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// // tile a row pattern across a [2, 2, 3] tensor
+	/// let pattern = ndarray::arr2(&[[0.0_f32, -1.0, -2.0]]);
+	/// let tensor = Tensor::broadcast_to(pattern.view(), &[2, 2, 3])?;
+	///
+	/// let (shape, data) = tensor.extract_raw_tensor();
+	/// assert_eq!(shape, [2, 2, 3]);
+	/// assert_eq!(data, &[0.0, -1.0, -2.0, 0.0, -1.0, -2.0, 0.0, -1.0, -2.0, 0.0, -1.0, -2.0]);
+	///
+	/// // [1, 3] cannot be broadcast to [2, 2]
+	/// assert!(Tensor::broadcast_to(pattern.view(), &[2, 2]).is_err());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - `target_shape` contains a negative dimension.
+	/// - `data` cannot be broadcast to `target_shape`.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn broadcast_to<D: Dimension>(data: ArrayView<'_, T, D>, target_shape: &[i64]) -> Result<Tensor<T>>
+	where
+		T: Clone + 'static
+	{
+		let target = target_shape
+			.iter()
+			.map(|&d| usize::try_from(d))
+			.collect::<Result<Vec<usize>, _>>()
+			.map_err(|_| Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot broadcast to shape {target_shape:?} with negative dimensions")))?;
+		let Some(broadcasted) = data.broadcast(IxDyn(&target)) else {
+			return Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Cannot broadcast array of shape {:?} to shape {target_shape:?}", data.shape())
+			));
+		};
+		Tensor::from_array(broadcasted.to_owned())
+	}
+}
+
+impl<T: NumericTensorElementType + Debug + 'static> Tensor<T> {
+	/// Construct a tensor by casting each element of a numeric [`ndarray::Array`] of a different type to `T`, e.g. to
+	/// feed `f64` data to a model which expects `f32` input.
+	///
+	/// Casts are checked with the same rules as [`Tensor::try_extract_as`] - see [`NumericTensorElementType`]. The data
+	/// is always copied into the new tensor.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let array = ndarray::arr2(&[[0.5_f64, 1.0], [1.5, 2.0]]);
+	/// let tensor = Tensor::<f32>::from_array_as(&array)?;
+	/// assert_eq!(tensor.extract_raw_tensor(), (vec![2, 2], &[0.5_f32, 1.0, 1.5, 2.0][..]));
+	///
+	/// // 1.5 is not a whole number
+	/// assert!(Tensor::<i64>::from_array_as(&array).is_err());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - Any element of `array` cannot be represented as `T`.
+	/// - Any dimension of `array` is zero.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn from_array_as<A: NumericTensorElementType, S: Data<Elem = A>, D: Dimension>(array: &ArrayBase<S, D>) -> Result<Tensor<T>> {
+		let data = array
+			.iter()
+			.enumerate()
+			.map(|(i, &x)| {
+				x.cast_into::<T>().ok_or_else(|| {
+					Error::new_with_code(
+						ErrorCode::InvalidArgument,
+						format!("Element {i} of Array<{}> cannot be represented as {}", A::into_tensor_element_type(), T::into_tensor_element_type())
+					)
+				})
+			})
+			.collect::<Result<Vec<T>>>()?;
+		Tensor::from_array((array.shape().to_vec(), data))
+	}
+}
+
+impl<T: PrimitiveTensorElementType + Copy + Debug + 'static> Tensor<T> {
+	/// Construct a 2-dimensional tensor of shape `[rows.len(), row_len]` from a slice of equal-length rows.
+	///
+	/// Rows can be given as anything that can be viewed as a slice, e.g. `&[&[T]]` or `&[Vec<T>]`.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let rows = vec![vec![1.0_f32, 2.0, 3.0], vec![4.0, 5.0, 6.0]];
+	/// let tensor = Tensor::from_rows(&rows)?;
+	///
+	/// let (shape, data) = tensor.extract_raw_tensor();
+	/// assert_eq!(shape, [2, 3]);
+	/// assert_eq!(data, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// Returns an error if there are no rows, if the rows are empty, or if the rows are not all the same length.
+	pub fn from_rows<R: AsRef<[T]>>(rows: &[R]) -> Result<Tensor<T>> {
+		let row_len = match rows.first() {
+			Some(row) => row.as_ref().len(),
+			None => return Err(Error::new_with_code(ErrorCode::InvalidArgument, "Cannot create a tensor from an empty list of rows"))
+		};
+
+		let mut data = Vec::with_capacity(rows.len() * row_len);
+		for (i, row) in rows.iter().enumerate() {
+			let row = row.as_ref();
+			if row.len() != row_len {
+				return Err(Error::new_with_code(
+					ErrorCode::InvalidArgument,
+					format!("Row {i} has {} elements, but the first row has {row_len}; all rows must be the same length", row.len())
+				));
+			}
+			data.extend_from_slice(row);
+		}
+
+		Tensor::from_array(([rows.len(), row_len], data))
+	}
+}
+
+impl Tensor<f32> {
+	/// Construct a dense 1-dimensional tensor of length `dim` from a sparse map of indices to values, e.g. a
+	/// bag-of-words or histogram feature vector. Indices not present in `counts` are zero.
+	///
+	/// ```
+	/// # use std::collections::HashMap;
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let counts = HashMap::from([(1, 2.0), (4, 0.5)]);
+	/// let tensor = Tensor::from_sparse_counts(5, &counts)?;
+	///
+	/// let (shape, data) = tensor.extract_raw_tensor();
+	/// assert_eq!(shape, [5]);
+	/// assert_eq!(data, &[0.0, 2.0, 0.0, 0.0, 0.5]);
+	///
+	/// assert!(Tensor::from_sparse_counts(4, &counts).is_err());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// Returns an error if any index in `counts` is out of bounds for a tensor of length `dim`.
+	pub fn from_sparse_counts(dim: usize, counts: &HashMap<usize, f32>) -> Result<Tensor<f32>> {
+		let mut data = vec![0.0; dim];
+		for (&index, &count) in counts {
+			match data.get_mut(index) {
+				Some(x) => *x = count,
+				None => {
+					return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Index {index} is out of bounds for a tensor of length {dim}")));
+				}
+			}
+		}
+		Tensor::from_array(([dim], data))
+	}
+
+	/// Construct a one-hot encoded tensor of shape `[indices.len(), num_classes]`, where row `i` is all zeros except
+	/// for a `1.0` at column `indices[i]`.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::one_hot(&[2, 0], 3)?;
+	///
+	/// let (shape, data) = tensor.extract_raw_tensor();
+	/// assert_eq!(shape, [2, 3]);
+	/// assert_eq!(data, &[0.0, 0.0, 1.0, 1.0, 0.0, 0.0]);
+	///
+	/// assert!(Tensor::one_hot(&[3], 3).is_err());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// Returns an error if `indices` is empty, if `num_classes` is 0, or if any index is negative or not less than
+	/// `num_classes`.
+	pub fn one_hot(indices: &[i64], num_classes: usize) -> Result<Tensor<f32>> {
+		let mut data = vec![0.0; indices.len() * num_classes];
+		for (row, &index) in indices.iter().enumerate() {
+			match usize::try_from(index) {
+				Ok(class) if class < num_classes => data[row * num_classes + class] = 1.0,
+				_ => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Class index {index} is out of range for {num_classes} classes")))
+			}
+		}
+		Tensor::from_array(([indices.len(), num_classes], data))
+	}
+}
+
+impl Tensor<bool> {
+	/// Construct a `bool` tensor of the given shape from raw bytes, e.g. a mask obtained from another library, copying
+	/// the data into the new tensor.
+	///
+	/// Like Rust's `bool`, ONNX Runtime stores each boolean element as a single byte that must be either `0` or `1`.
+	/// Any other byte would be undefined behavior to read as a `bool`, so it is rejected with an error instead; to
+	/// convert arbitrary nonzero bytes to `true`, map them with `b != 0` and use [`Tensor::from_array`].
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_bool_bytes([2, 2], &[0, 1, 1, 0])?;
+	/// assert_eq!(tensor.extract_raw_tensor().1, &[false, true, true, false]);
+	///
+	/// assert!(Tensor::from_bool_bytes([2], &[1, 2]).is_err());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - Any byte of `data` is not `0` or `1`.
+	/// - Any dimension of `shape` is less than 1, or the number of elements described by `shape` does not match the
+	///   length of `data`.
+	pub fn from_bool_bytes(shape: impl ToDimensions, data: &[u8]) -> Result<Tensor<bool>> {
+		if let Some(i) = data.iter().position(|&b| b > 1) {
+			return Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Invalid boolean byte {:#04x} at index {i}; boolean tensor data must only contain 0 or 1", data[i])
+			));
+		}
+		Tensor::from_iter_exact(shape, data.iter().map(|&b| b == 1))
+	}
+}
+
+/// The rounding mode used when narrowing `f32` values to a lower-precision floating point type, e.g. in
+/// [`Tensor::<half::bf16>::from_f32_slice`](Tensor::from_f32_slice).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+	/// Round to the nearest representable value, rounding ties to the value with an even mantissa. This is the IEEE 754
+	/// default, and matches the behavior of ONNX Runtime's own `Cast` operator.
+	#[default]
+	NearestEven,
+	/// Truncate the discarded mantissa bits, rounding towards zero. Some accelerators narrow values this way, so this
+	/// mode may be necessary to reproduce their results exactly.
+	Truncate
+}
+
+#[cfg(feature = "half")]
+#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+impl Tensor<half::bf16> {
+	/// Construct a `bf16` tensor of the given shape from `f32` data, narrowing each element with the given
+	/// [`Rounding`] mode.
+	///
+	/// `NaN`s remain `NaN` regardless of the rounding mode.
+	///
+	/// ```
+	/// # use ort::{Rounding, Tensor};
+	/// # fn main() -> ort::Result<()> {
+	/// // 1.00390625 lies exactly halfway between the bf16 values 1.0 and 1.0078125
+	/// let data = [1.00390625_f32, -1.01171875];
+	///
+	/// let tensor = Tensor::<half::bf16>::from_f32_slice([2], &data, Rounding::NearestEven)?;
+	/// assert_eq!(tensor.extract_raw_tensor().1, &[half::bf16::from_f32(1.0), half::bf16::from_f32(-1.015625)]);
+	///
+	/// let tensor = Tensor::<half::bf16>::from_f32_slice([2], &data, Rounding::Truncate)?;
+	/// assert_eq!(tensor.extract_raw_tensor().1, &[half::bf16::from_f32(1.0), half::bf16::from_f32(-1.0078125)]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// Returns an error if any dimension of `shape` is less than 1, or if the number of elements described by `shape`
+	/// does not match the length of `data`.
+	pub fn from_f32_slice(shape: impl ToDimensions, data: &[f32], rounding: Rounding) -> Result<Tensor<half::bf16>> {
+		let narrow = match rounding {
+			Rounding::NearestEven => half::bf16::from_f32,
+			Rounding::Truncate => truncate_to_bf16
+		};
+		Tensor::from_iter_exact(shape, data.iter().map(|&x| narrow(x)))
+	}
+}
+
+/// Narrows `x` to a `bf16` by dropping the low 16 bits of its representation.
+#[cfg(feature = "half")]
+fn truncate_to_bf16(x: f32) -> half::bf16 {
+	// a NaN whose payload is only in the low bits would otherwise truncate to infinity
+	if x.is_nan() { half::bf16::NAN } else { half::bf16::from_bits((x.to_bits() >> 16) as u16) }
+}
+
+impl DynTensor {
+	/// Quantize `f32` data into a new integer tensor of type `dtype` using the affine quantization formula
+	/// `clamp(round(x / scale) + zero_point)`, matching the semantics of ONNX's `QuantizeLinear` operator.
+	///
+	/// Values are rounded half to even, and then saturated to the range of the target type, so out-of-range inputs
+	/// will never wrap around. `NaN` inputs quantize to 0.
+	///
+	/// The supported target types are [`TensorElementType::Int8`], [`TensorElementType::Uint8`],
+	/// [`TensorElementType::Int16`], [`TensorElementType::Uint16`], and [`TensorElementType::Int32`].
+	///
+	/// ```
+	/// # use ort::{DynTensor, TensorElementType};
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = DynTensor::quantize_from_f32([4], &[-1.0, 0.25, 0.5, 100.0], TensorElementType::Uint8, 0.5, 128)?;
+	///
+	/// let (_, data) = tensor.try_extract_raw_tensor::<u8>()?;
+	/// assert_eq!(data, &[126, 128, 129, 255]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - `dtype` is not one of the supported integer types listed above.
+	/// - `scale` is zero or not finite.
+	/// - `zero_point` is not representable by `dtype`.
+	/// - `shape` does not match the length of `data`.
+	pub fn quantize_from_f32(shape: impl ToDimensions, data: &[f32], dtype: TensorElementType, scale: f32, zero_point: i32) -> Result<DynTensor> {
+		if scale == 0.0 || !scale.is_finite() {
+			return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Quantization scale must be finite and non-zero, got {scale}")));
+		}
+
+		let shape = shape.to_dimensions(Some(data.len()))?;
+
+		macro_rules! quantize {
+			($t:ty) => {{
+				if <$t>::try_from(zero_point).is_err() {
+					return Err(Error::new_with_code(
+						ErrorCode::InvalidArgument,
+						format!("Zero point {zero_point} is out of range for a tensor of type {dtype}")
+					));
+				}
+
+				let quantized: Vec<$t> = data
+					.iter()
+					.map(|&x| (round_half_to_even(x / scale) as f64 + zero_point as f64).clamp(<$t>::MIN as f64, <$t>::MAX as f64) as $t)
+					.collect();
+				Tensor::from_array((shape, quantized)).map(|c| c.upcast())
+			}};
+		}
+
+		match dtype {
+			TensorElementType::Int8 => quantize!(i8),
+			TensorElementType::Uint8 => quantize!(u8),
+			TensorElementType::Int16 => quantize!(i16),
+			TensorElementType::Uint16 => quantize!(u16),
+			TensorElementType::Int32 => quantize!(i32),
+			_ => Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Cannot quantize into a tensor of type {dtype}; expected an 8, 16, or 32-bit integer type")
+			))
+		}
+	}
+}
+
+/// Rounds `x` to the nearest integer, rounding ties to the nearest even integer, as required by `QuantizeLinear`.
+///
+/// `f32::round_ties_even` is only available as of Rust 1.77, which is past our MSRV.
+fn round_half_to_even(x: f32) -> f32 {
+	let rounded = x.round();
+	if (x - x.trunc()).abs() == 0.5 { 2.0 * (x / 2.0).round() } else { rounded }
 }
 
 impl<'a, T: PrimitiveTensorElementType + Debug> TensorRefMut<'a, T> {
@@ -254,7 +803,9 @@ impl<'a, T: PrimitiveTensorElementType + Debug> TensorRefMut<'a, T> {
 			inner: Arc::new(ValueInner::CppOwned {
 				ptr: unsafe { NonNull::new_unchecked(value_ptr) },
 				drop: true,
-				_session: None
+				borrowed: true,
+				_session: None,
+				dims: OnceLock::new()
 			}),
 			_markers: PhantomData
 		}))
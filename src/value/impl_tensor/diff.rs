@@ -0,0 +1,114 @@
+use super::TensorValueTypeMarker;
+use crate::{
+	error::{Error, ErrorCode, Result},
+	tensor::TensorElementType,
+	value::{Value, ValueType}
+};
+
+/// A summary of the element-wise differences between two tensors, as returned by
+/// [`Tensor::diff_summary`](crate::Tensor::diff_summary).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiffSummary {
+	/// The largest absolute difference between any pair of elements.
+	pub max_abs_diff: f64,
+	/// The mean absolute difference over all pairs of elements.
+	pub mean_abs_diff: f64,
+	/// The number of pairs of elements whose absolute difference is greater than the threshold.
+	pub num_exceeding: usize
+}
+
+impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
+	/// Compares this tensor element-wise with another numeric tensor of the same shape, summarizing how much they
+	/// differ. This is useful for regression testing, e.g. to check that a re-exported or quantized model's outputs
+	/// have not drifted too far from the original's.
+	///
+	/// The tensors may have different element types; elements are compared as `f64`s. Two NaN elements are considered
+	/// equal, while a NaN element compared with a non-NaN element has an infinite difference.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let before = Value::from_array(([4usize], vec![1.0_f32, 2.0, 3.0, 4.0].into_boxed_slice()))?;
+	/// let after = Value::from_array(([4usize], vec![1.0_f32, 2.5, 3.0, 3.0].into_boxed_slice()))?;
+	///
+	/// let diff = before.diff_summary(&after, 0.75)?;
+	/// assert_eq!(diff.max_abs_diff, 1.0);
+	/// assert_eq!(diff.mean_abs_diff, 0.375);
+	/// assert_eq!(diff.num_exceeding, 1);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - Either value is not a tensor, or is a boolean or string tensor.
+	/// - The tensors have different shapes.
+	/// - Either tensor's data is not allocated in CPU memory.
+	pub fn diff_summary<OtherType: TensorValueTypeMarker + ?Sized>(&self, other: &Value<OtherType>, threshold: f64) -> Result<DiffSummary> {
+		let (shape, a) = self.to_f64_vec()?;
+		let (other_shape, b) = other.to_f64_vec()?;
+		if shape != other_shape {
+			return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot compare tensors of different shapes {shape:?} and {other_shape:?}")));
+		}
+
+		let mut summary = DiffSummary {
+			max_abs_diff: 0.0,
+			mean_abs_diff: 0.0,
+			num_exceeding: 0
+		};
+		let mut total = 0.0;
+		for (a, b) in a.iter().zip(&b) {
+			let diff = match (a.is_nan(), b.is_nan()) {
+				(true, true) => 0.0,
+				(false, false) => (a - b).abs(),
+				_ => f64::INFINITY
+			};
+			summary.max_abs_diff = summary.max_abs_diff.max(diff);
+			if diff > threshold {
+				summary.num_exceeding += 1;
+			}
+			total += diff;
+		}
+		if !a.is_empty() {
+			summary.mean_abs_diff = total / a.len() as f64;
+		}
+		Ok(summary)
+	}
+
+	/// Copies the data of this numeric tensor into a `Vec<f64>`, returning it along with the tensor's shape.
+	pub(super) fn to_f64_vec(&self) -> Result<(Vec<i64>, Vec<f64>)> {
+		let ty = match self.try_dtype()? {
+			ValueType::Tensor { ty, .. } => ty,
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot convert {t} to f64; expected a tensor")))
+		};
+
+		macro_rules! to_f64 {
+			($t:ty, $conv:expr) => {{
+				let (shape, data) = self.try_extract_raw_tensor::<$t>()?;
+				(shape, data.iter().map(|&x| $conv(x)).collect())
+			}};
+		}
+
+		Ok(match ty {
+			TensorElementType::Float32 => to_f64!(f32, f64::from),
+			TensorElementType::Float64 => to_f64!(f64, f64::from),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 => to_f64!(half::f16, half::f16::to_f64),
+			#[cfg(feature = "half")]
+			TensorElementType::Bfloat16 => to_f64!(half::bf16, half::bf16::to_f64),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN => to_f64!(float8::F8E4M3, |x: float8::F8E4M3| x.to_f64()),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E5M2 => to_f64!(float8::F8E5M2, |x: float8::F8E5M2| x.to_f64()),
+			TensorElementType::Int8 => to_f64!(i8, f64::from),
+			TensorElementType::Int16 => to_f64!(i16, f64::from),
+			TensorElementType::Int32 => to_f64!(i32, f64::from),
+			TensorElementType::Int64 => to_f64!(i64, |x| x as f64),
+			TensorElementType::Uint8 => to_f64!(u8, f64::from),
+			TensorElementType::Uint16 => to_f64!(u16, f64::from),
+			TensorElementType::Uint32 => to_f64!(u32, f64::from),
+			TensorElementType::Uint64 => to_f64!(u64, |x| x as f64),
+			_ => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot convert Tensor<{ty}> to f64; expected a real numeric tensor")))
+		})
+	}
+}
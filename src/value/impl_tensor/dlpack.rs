@@ -0,0 +1,376 @@
+//! [DLPack](https://dmlc.github.io/dlpack/latest/) interop, for exchanging tensors with other frameworks (like PyTorch,
+//! JAX, or CuPy) without copying.
+//!
+//! This module contains the DLPack ABI types (as of DLPack v0.8), so no additional dependencies are required.
+
+use std::{
+	ffi::c_void,
+	marker::PhantomData,
+	ptr::{self, NonNull},
+	sync::{Arc, OnceLock}
+};
+
+use super::{DynTensor, TensorValueTypeMarker};
+use crate::{
+	error::{Error, ErrorCode, Result},
+	memory::{AllocationDevice, AllocatorType, MemoryInfo, MemoryType},
+	ortsys,
+	tensor::TensorElementType,
+	value::{Value, ValueInner, ValueType}
+};
+
+/// The type of device a [`DLTensor`]'s data resides on.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DLDeviceType(pub i32);
+
+impl DLDeviceType {
+	pub const CPU: DLDeviceType = DLDeviceType(1);
+	pub const CUDA: DLDeviceType = DLDeviceType(2);
+	/// Pinned CUDA CPU memory, allocated by `cudaMallocHost`.
+	pub const CUDA_HOST: DLDeviceType = DLDeviceType(3);
+	pub const OPENCL: DLDeviceType = DLDeviceType(4);
+	pub const VULKAN: DLDeviceType = DLDeviceType(7);
+	pub const METAL: DLDeviceType = DLDeviceType(8);
+	pub const VPI: DLDeviceType = DLDeviceType(9);
+	pub const ROCM: DLDeviceType = DLDeviceType(10);
+	/// Pinned ROCm CPU memory, allocated by `hipMallocHost`.
+	pub const ROCM_HOST: DLDeviceType = DLDeviceType(11);
+	pub const EXT_DEV: DLDeviceType = DLDeviceType(12);
+	pub const CUDA_MANAGED: DLDeviceType = DLDeviceType(13);
+	pub const ONE_API: DLDeviceType = DLDeviceType(14);
+	pub const WEBGPU: DLDeviceType = DLDeviceType(15);
+	pub const HEXAGON: DLDeviceType = DLDeviceType(16);
+}
+
+/// A device a [`DLTensor`]'s data resides on.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DLDevice {
+	pub device_type: DLDeviceType,
+	pub device_id: i32
+}
+
+/// The kind of a [`DLDataType`].
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DLDataTypeCode(pub u8);
+
+impl DLDataTypeCode {
+	pub const INT: DLDataTypeCode = DLDataTypeCode(0);
+	pub const UINT: DLDataTypeCode = DLDataTypeCode(1);
+	pub const FLOAT: DLDataTypeCode = DLDataTypeCode(2);
+	pub const OPAQUE_HANDLE: DLDataTypeCode = DLDataTypeCode(3);
+	pub const BFLOAT: DLDataTypeCode = DLDataTypeCode(4);
+	pub const COMPLEX: DLDataTypeCode = DLDataTypeCode(5);
+	pub const BOOL: DLDataTypeCode = DLDataTypeCode(6);
+}
+
+/// The element type of a [`DLTensor`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DLDataType {
+	pub code: DLDataTypeCode,
+	/// The number of bits of each lane.
+	pub bits: u8,
+	/// The number of lanes, for vector types; always `1` for tensors exported by `ort`.
+	pub lanes: u16
+}
+
+/// A borrowed view of a tensor's data.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLTensor {
+	pub data: *mut c_void,
+	pub device: DLDevice,
+	pub ndim: i32,
+	pub dtype: DLDataType,
+	/// Pointer to an array of `ndim` dimensions.
+	pub shape: *mut i64,
+	/// Pointer to an array of `ndim` strides, in elements. May be null, in which case the tensor is compact and
+	/// row-major.
+	pub strides: *mut i64,
+	/// Offset, in bytes, from `data` to the first element of the tensor.
+	pub byte_offset: u64
+}
+
+/// A [`DLTensor`] along with the context required to free it.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLManagedTensor {
+	pub dl_tensor: DLTensor,
+	pub manager_ctx: *mut c_void,
+	/// Releases the tensor. Must be called exactly once by the consumer when it is done with the tensor.
+	pub deleter: Option<unsafe extern "C" fn(*mut DLManagedTensor)>
+}
+
+fn dl_data_type(ty: TensorElementType) -> Option<DLDataType> {
+	let code = match ty {
+		TensorElementType::Float32 | TensorElementType::Float64 => DLDataTypeCode::FLOAT,
+		#[cfg(feature = "half")]
+		TensorElementType::Float16 => DLDataTypeCode::FLOAT,
+		#[cfg(feature = "half")]
+		TensorElementType::Bfloat16 => DLDataTypeCode::BFLOAT,
+		TensorElementType::Int8 | TensorElementType::Int16 | TensorElementType::Int32 | TensorElementType::Int64 => DLDataTypeCode::INT,
+		TensorElementType::Uint8 | TensorElementType::Uint16 | TensorElementType::Uint32 | TensorElementType::Uint64 => DLDataTypeCode::UINT,
+		TensorElementType::Bool => DLDataTypeCode::BOOL,
+		#[cfg(feature = "complex")]
+		TensorElementType::Complex64 | TensorElementType::Complex128 => DLDataTypeCode::COMPLEX,
+		// the version of DLPack implemented here has no 8-bit float type codes
+		#[cfg(feature = "fp8")]
+		TensorElementType::Float8E4M3FN | TensorElementType::Float8E5M2 => return None,
+		TensorElementType::String => return None
+	};
+	Some(DLDataType {
+		code,
+		bits: (ty.try_byte_size()? * 8) as u8,
+		lanes: 1
+	})
+}
+
+fn element_type(dtype: DLDataType) -> Option<TensorElementType> {
+	if dtype.lanes != 1 {
+		return None;
+	}
+	Some(match (dtype.code, dtype.bits) {
+		(DLDataTypeCode::FLOAT, 32) => TensorElementType::Float32,
+		(DLDataTypeCode::FLOAT, 64) => TensorElementType::Float64,
+		#[cfg(feature = "half")]
+		(DLDataTypeCode::FLOAT, 16) => TensorElementType::Float16,
+		#[cfg(feature = "half")]
+		(DLDataTypeCode::BFLOAT, 16) => TensorElementType::Bfloat16,
+		(DLDataTypeCode::INT, 8) => TensorElementType::Int8,
+		(DLDataTypeCode::INT, 16) => TensorElementType::Int16,
+		(DLDataTypeCode::INT, 32) => TensorElementType::Int32,
+		(DLDataTypeCode::INT, 64) => TensorElementType::Int64,
+		(DLDataTypeCode::UINT, 8) => TensorElementType::Uint8,
+		(DLDataTypeCode::UINT, 16) => TensorElementType::Uint16,
+		(DLDataTypeCode::UINT, 32) => TensorElementType::Uint32,
+		(DLDataTypeCode::UINT, 64) => TensorElementType::Uint64,
+		(DLDataTypeCode::BOOL, 8) => TensorElementType::Bool,
+		#[cfg(feature = "complex")]
+		(DLDataTypeCode::COMPLEX, 64) => TensorElementType::Complex64,
+		#[cfg(feature = "complex")]
+		(DLDataTypeCode::COMPLEX, 128) => TensorElementType::Complex128,
+		_ => return None
+	})
+}
+
+/// Keeps an exported tensor's value alive until the consumer calls the deleter.
+struct ExportContext {
+	_value: Arc<ValueInner>,
+	shape: Vec<i64>,
+	strides: Vec<i64>
+}
+
+unsafe extern "C" fn delete_exported(tensor: *mut DLManagedTensor) {
+	if tensor.is_null() {
+		return;
+	}
+	let tensor = unsafe { Box::from_raw(tensor) };
+	drop(unsafe { Box::from_raw(tensor.manager_ctx.cast::<ExportContext>()) });
+}
+
+impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
+	/// Exports this tensor as a [`DLManagedTensor`] without copying its data, so it can be consumed by other frameworks
+	/// which support [DLPack](https://dmlc.github.io/dlpack/latest/).
+	///
+	/// The returned tensor keeps this tensor's data alive until its `deleter` is called, even if this `Value` is
+	/// dropped first. Ownership of the returned pointer is transferred to the caller, who must eventually call its
+	/// `deleter` exactly once (typically by handing it to the consuming framework, which will do so for you).
+	///
+	/// The exported tensor shares its data with this tensor, so the consumer should not write to it while this tensor
+	/// is still in use.
+	///
+	/// ```
+	/// # use ort::{Tensor, dlpack::{DLDeviceType, DLDataTypeCode}};
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_array(([2usize, 3], vec![1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0].into_boxed_slice()))?;
+	///
+	/// let managed = tensor.to_dlpack()?;
+	/// unsafe {
+	/// 	let dl_tensor = &(*managed).dl_tensor;
+	/// 	assert_eq!(dl_tensor.device.device_type, DLDeviceType::CPU);
+	/// 	assert_eq!(dl_tensor.dtype.code, DLDataTypeCode::FLOAT);
+	/// 	assert_eq!(std::slice::from_raw_parts(dl_tensor.shape, 2), &[2, 3]);
+	/// 	assert_eq!(std::slice::from_raw_parts(dl_tensor.strides, 2), &[3, 1]);
+	///
+	/// 	((*managed).deleter.unwrap())(managed);
+	/// }
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is a string tensor, which DLPack cannot represent.
+	/// - The tensor is allocated on a device which has no DLPack equivalent (e.g. DirectML).
+	/// - The tensor does not own its data (see [`Value::owns_data`]), like views created with
+	///   [`crate::Tensor::from_slice`] or values passed to operator kernels, since the exported tensor could then
+	///   outlive the memory it references.
+	#[cfg_attr(docsrs, doc(cfg(feature = "dlpack")))]
+	pub fn to_dlpack(&self) -> Result<*mut DLManagedTensor> {
+		// Like `ValueRef::try_upgrade`, the export would keep the value alive past the borrow (or kernel invocation) its
+		// data is only valid for, allowing a UAF.
+		if let ValueInner::CppOwned { drop: false, .. } | ValueInner::CppOwned { borrowed: true, .. } = &*self.inner {
+			return Err(Error::new_with_code(ErrorCode::InvalidArgument, "Cannot export a tensor which does not own its data to DLPack"));
+		}
+
+		let (ty, dimensions) = match self.try_dtype()? {
+			ValueType::Tensor { ty, dimensions } => (ty, dimensions),
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot export {t} to DLPack; expected a tensor")))
+		};
+		let dtype = dl_data_type(ty).ok_or_else(|| Error::new_with_code(ErrorCode::InvalidArgument, format!("Tensor<{ty}> cannot be exported to DLPack")))?;
+
+		let memory_info = self.memory_info();
+		let device_id = memory_info.device_id();
+		let device_type = match memory_info.allocation_device() {
+			AllocationDevice::CPU => DLDeviceType::CPU,
+			AllocationDevice::CUDA => DLDeviceType::CUDA,
+			AllocationDevice::CUDA_PINNED => DLDeviceType::CUDA_HOST,
+			AllocationDevice::HIP => DLDeviceType::ROCM,
+			AllocationDevice::HIP_PINNED => DLDeviceType::ROCM_HOST,
+			device => return Err(Error::new(format!("Cannot export a tensor on device `{}` to DLPack", device.as_str())))
+		};
+
+		let mut context = Box::new(ExportContext {
+			_value: Arc::clone(&self.inner),
+			strides: self.strides()?.into_iter().map(|s| s as i64).collect(),
+			shape: dimensions
+		});
+		let managed = Box::new(DLManagedTensor {
+			dl_tensor: DLTensor {
+				data: self.data_ptr()?.cast_mut(),
+				device: DLDevice { device_type, device_id },
+				ndim: context.shape.len() as i32,
+				dtype,
+				shape: context.shape.as_mut_ptr(),
+				strides: context.strides.as_mut_ptr(),
+				byte_offset: 0
+			},
+			manager_ctx: std::ptr::null_mut(),
+			deleter: Some(delete_exported)
+		});
+		let managed = Box::into_raw(managed);
+		// the shape & strides buffers are heap allocated, so moving the box into a raw pointer doesn't invalidate them
+		unsafe { (*managed).manager_ctx = Box::into_raw(context).cast() };
+		Ok(managed)
+	}
+}
+
+/// Calls an imported tensor's deleter when the [`Value`] borrowing its data is dropped.
+struct ImportGuard(*mut DLManagedTensor);
+
+impl Drop for ImportGuard {
+	fn drop(&mut self) {
+		if let Some(deleter) = unsafe { (*self.0).deleter } {
+			unsafe { deleter(self.0) };
+		}
+	}
+}
+
+impl DynTensor {
+	/// Imports a [`DLManagedTensor`] produced by another framework which supports
+	/// [DLPack](https://dmlc.github.io/dlpack/latest/), without copying its data.
+	///
+	/// The returned tensor borrows the DLPack tensor's data, and calls its `deleter` when it is dropped. Ownership of
+	/// `tensor` is always transferred to this function; if an error is returned, the deleter is called immediately.
+	///
+	/// ```
+	/// # use ort::{DynTensor, Tensor};
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_array(([2usize, 2], vec![1_i64, 2, 3, 4].into_boxed_slice()))?;
+	///
+	/// let imported = unsafe { DynTensor::from_dlpack(tensor.to_dlpack()?)? };
+	/// assert_eq!(imported.try_extract_raw_tensor::<i64>()?, (vec![2, 2], &[1, 2, 3, 4][..]));
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - `tensor` is null.
+	/// - The tensor's element type has no ONNX Runtime equivalent (e.g. multi-lane types), or its equivalent requires a
+	///   crate feature which is not enabled (`complex` for complex types, or `half` for `f16` & `bf16`).
+	/// - The tensor's device has no ONNX Runtime equivalent (e.g. Vulkan).
+	/// - The tensor is not compact & row-major, as ONNX Runtime does not support strided tensors.
+	///
+	/// # Safety
+	/// `tensor` must point to a valid [`DLManagedTensor`] whose data, shape, and strides remain valid until its deleter
+	/// is called, and which has not already been consumed.
+	#[cfg_attr(docsrs, doc(cfg(feature = "dlpack")))]
+	pub unsafe fn from_dlpack(tensor: *mut DLManagedTensor) -> Result<DynTensor> {
+		if tensor.is_null() {
+			return Err(Error::new_with_code(ErrorCode::InvalidArgument, "Cannot import a null DLPack tensor"));
+		}
+		let guard = ImportGuard(tensor);
+		let dl_tensor = unsafe { &(*tensor).dl_tensor };
+
+		let ty = element_type(dl_tensor.dtype).ok_or_else(|| {
+			Error::new_with_code(ErrorCode::InvalidArgument, format!("DLPack data type {:?} has no ONNX Runtime equivalent", dl_tensor.dtype))
+		})?;
+		let device_id = dl_tensor.device.device_id;
+		let memory_info = match dl_tensor.device.device_type {
+			DLDeviceType::CPU => MemoryInfo::new(AllocationDevice::CPU, 0, AllocatorType::Arena, MemoryType::CPUInput)?,
+			DLDeviceType::CUDA => MemoryInfo::new(AllocationDevice::CUDA, device_id, AllocatorType::Device, MemoryType::Default)?,
+			DLDeviceType::CUDA_HOST => MemoryInfo::new(AllocationDevice::CUDA_PINNED, device_id, AllocatorType::Device, MemoryType::CPUInput)?,
+			DLDeviceType::ROCM => MemoryInfo::new(AllocationDevice::HIP, device_id, AllocatorType::Device, MemoryType::Default)?,
+			DLDeviceType::ROCM_HOST => MemoryInfo::new(AllocationDevice::HIP_PINNED, device_id, AllocatorType::Device, MemoryType::CPUInput)?,
+			device => return Err(Error::new(format!("DLPack device type {} has no ONNX Runtime equivalent", device.0)))
+		};
+
+		let ndim = usize::try_from(dl_tensor.ndim).map_err(|_| Error::new_with_code(ErrorCode::InvalidArgument, "DLPack tensor has negative rank"))?;
+		let shape = if ndim == 0 {
+			Vec::new()
+		} else {
+			unsafe { std::slice::from_raw_parts(dl_tensor.shape, ndim) }.to_vec()
+		};
+		if !dl_tensor.strides.is_null() && ndim > 0 {
+			let strides = unsafe { std::slice::from_raw_parts(dl_tensor.strides, ndim) };
+			let mut expected = 1;
+			for (&dim, &stride) in shape.iter().zip(strides).rev() {
+				// the stride of a dimension of size 1 is irrelevant, and some frameworks don't normalize it
+				if dim != 1 && stride != expected {
+					return Err(Error::new_with_code(
+						ErrorCode::InvalidArgument,
+						format!("DLPack tensor with shape {shape:?} and strides {strides:?} is not compact & row-major")
+					));
+				}
+				expected *= dim;
+			}
+		}
+
+		let element_count = shape
+			.iter()
+			.try_fold(1usize, |acc, &dim| usize::try_from(dim).ok().and_then(|dim| acc.checked_mul(dim)));
+		let data_len = element_count
+			.and_then(|count| count.checked_mul(usize::from(dl_tensor.dtype.bits) / 8))
+			.ok_or_else(|| Error::new_with_code(ErrorCode::InvalidArgument, format!("DLPack tensor has invalid shape {shape:?}")))?;
+		let data = unsafe { dl_tensor.data.cast::<u8>().add(dl_tensor.byte_offset as usize) };
+
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+		ortsys![
+			unsafe CreateTensorWithDataAsOrtValue(
+				memory_info.ptr.as_ptr(),
+				data.cast(),
+				data_len as _,
+				shape.as_ptr(),
+				shape.len() as _,
+				ty.into(),
+				&mut value_ptr
+			)?;
+			nonNull(value_ptr)
+		];
+
+		Ok(Value {
+			inner: Arc::new(ValueInner::RustOwned {
+				ptr: unsafe { NonNull::new_unchecked(value_ptr) },
+				_array: Box::new(guard),
+				_memory_info: Some(memory_info),
+				dims: OnceLock::new()
+			}),
+			_markers: PhantomData
+		})
+	}
+}
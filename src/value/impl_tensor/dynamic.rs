@@ -0,0 +1,142 @@
+use std::ptr::{self, NonNull};
+
+use super::{TensorValueTypeMarker, calculate_tensor_size};
+use crate::{
+	error::{Error, ErrorCode, Result},
+	ortsys,
+	tensor::{PrimitiveTensorElementType, TensorElementType},
+	value::{Value, ValueType, extract_data_type_from_tensor_info}
+};
+
+/// The data of a tensor of any element type, as returned by
+/// [`Tensor::try_extract_dynamic`](crate::Tensor::try_extract_dynamic).
+#[derive(Debug, Clone, PartialEq)]
+pub enum TensorData<'v> {
+	/// A view of the data of a tensor with a primitive (i.e. non-string) element type.
+	PrimitiveView {
+		/// The tensor's element type, which determines how `data` should be interpreted.
+		ty: TensorElementType,
+		shape: Vec<i64>,
+		/// The tensor's raw data, in row-major order. Each element occupies [`TensorElementType::byte_size`] bytes.
+		data: &'v [u8]
+	},
+	/// The decoded data of a string tensor.
+	Strings { shape: Vec<i64>, data: Vec<String> }
+}
+
+impl<'v> TensorData<'v> {
+	/// Returns the shape of the tensor.
+	pub fn shape(&self) -> &[i64] {
+		match self {
+			TensorData::PrimitiveView { shape, .. } | TensorData::Strings { shape, .. } => shape
+		}
+	}
+
+	/// Returns the element type of the tensor.
+	pub fn element_type(&self) -> TensorElementType {
+		match self {
+			TensorData::PrimitiveView { ty, .. } => *ty,
+			TensorData::Strings { .. } => TensorElementType::String
+		}
+	}
+
+	/// Returns a pointer to the data of a primitive tensor view along with its number of elements, suitable for
+	/// passing to C APIs, or `None` if this is a string tensor or `T` does not match the tensor's element type.
+	///
+	/// The pointer is only valid for as long as the tensor this view borrows from.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_array(([3usize], vec![1.0_f32, 2.0, 3.0]))?;
+	/// let data = tensor.try_extract_dynamic()?;
+	///
+	/// let (ptr, len) = data.as_ptr_len::<f32>().unwrap();
+	/// assert_eq!(unsafe { std::slice::from_raw_parts(ptr, len) }, &[1.0, 2.0, 3.0]);
+	/// assert!(data.as_ptr_len::<i32>().is_none());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	pub fn as_ptr_len<T: PrimitiveTensorElementType>(&self) -> Option<(*const T, usize)> {
+		match self {
+			TensorData::PrimitiveView { ty, data, .. } if *ty == T::into_tensor_element_type() => {
+				// the data of an empty tensor is a dangling `u8` pointer, which may not be aligned for `T`
+				let ptr = if data.is_empty() { NonNull::dangling().as_ptr() } else { data.as_ptr().cast::<T>() };
+				Some((ptr, data.len() / ty.byte_size()))
+			}
+			_ => None
+		}
+	}
+}
+
+impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
+	/// Attempt to extract the underlying data of a tensor of any element type, without knowing its type in advance.
+	///
+	/// Unlike [`Value::dtype`], this never panics for tensors whose element type requires a crate feature that is not
+	/// enabled in this build (e.g. `f16` tensors without the `half` feature, or complex tensors without the `complex`
+	/// feature); instead, an error with [`ErrorCode::UnsupportedTensorElementType`] naming the required feature is
+	/// returned.
+	///
+	/// ```
+	/// # use ort::{Tensor, TensorData, TensorElementType};
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_array(([2usize], vec![1_i16, -1]))?;
+	/// match tensor.try_extract_dynamic()? {
+	/// 	TensorData::PrimitiveView { ty, shape, data } => {
+	/// 		assert_eq!(ty, TensorElementType::Int16);
+	/// 		assert_eq!(shape, [2]);
+	/// 		assert_eq!(data, [1_i16.to_ne_bytes(), (-1_i16).to_ne_bytes()].concat());
+	/// 	}
+	/// 	TensorData::Strings { .. } => unreachable!()
+	/// }
+	///
+	/// let tensor = Tensor::from_string_array(([1usize], vec!["hello"].into_boxed_slice()))?;
+	/// assert_eq!(
+	/// 	tensor.try_extract_dynamic()?,
+	/// 	TensorData::Strings {
+	/// 		shape: vec![1],
+	/// 		data: vec!["hello".to_string()]
+	/// 	}
+	/// );
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor's element type is not supported by this build.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[doc(alias = "extract_dynamic")]
+	pub fn try_extract_dynamic(&self) -> Result<TensorData<'_>> {
+		if !self.is_tensor()? {
+			return Err(Error::new_with_code(ErrorCode::InvalidArgument, "Cannot extract tensor data from a non-tensor value"));
+		}
+
+		let mut info_ptr: *mut ort_sys::OrtTensorTypeAndShapeInfo = ptr::null_mut();
+		ortsys![unsafe GetTensorTypeAndShape(self.ptr(), &mut info_ptr)?; nonNull(info_ptr)];
+		let dtype = unsafe { extract_data_type_from_tensor_info(info_ptr) };
+		ortsys![unsafe ReleaseTensorTypeAndShapeInfo(info_ptr)];
+		let ValueType::Tensor { ty, dimensions: shape } = dtype? else {
+			unreachable!()
+		};
+
+		let Some(width) = ty.try_byte_size() else {
+			let (shape, data) = self.try_extract_raw_string_tensor()?;
+			return Ok(TensorData::Strings { shape, data });
+		};
+
+		let mem = self.memory_info();
+		if !mem.is_cpu_accessible() {
+			return Err(Error::new(format!("Cannot extract from value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
+		}
+
+		let len = calculate_tensor_size(&shape) * width;
+		let data = if len == 0 {
+			&[][..]
+		} else {
+			unsafe { std::slice::from_raw_parts(self.data_ptr()?.cast::<u8>(), len) }
+		};
+		Ok(TensorData::PrimitiveView { ty, shape, data })
+	}
+}
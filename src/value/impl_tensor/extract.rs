@@ -1,11 +1,18 @@
-use std::{fmt::Debug, ptr, string::FromUtf8Error};
+use std::{
+	collections::{HashMap, VecDeque},
+	fmt::Debug,
+	mem::MaybeUninit,
+	ptr,
+	str::Utf8Error,
+	sync::Arc
+};
 
 #[cfg(feature = "ndarray")]
-use ndarray::IxDyn;
+use ndarray::{Axis, IxDyn, ShapeBuilder};
 
 use super::{Tensor, TensorValueTypeMarker, calculate_tensor_size};
 #[cfg(feature = "ndarray")]
-use crate::tensor::{extract_primitive_array, extract_primitive_array_mut};
+use crate::tensor::{NumericTensorElementType, extract_primitive_array, extract_primitive_array_mut};
 use crate::{
 	error::{Error, ErrorCode, Result},
 	ortsys,
@@ -34,6 +41,34 @@ impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
 	/// # }
 	/// ```
 	///
+	/// ## Half-precision tensors
+	/// With the `half` feature enabled (which it is by default), `f16` and `bf16` tensors can be extracted directly as
+	/// [`half::f16`] and [`half::bf16`], without widening them (and losing the ability to perform further
+	/// half-precision math):
+	/// ```
+	/// # use ort::Value;
+	/// # #[cfg(feature = "half")]
+	/// # fn main() -> ort::Result<()> {
+	/// use half::f16;
+	///
+	/// let value = Value::from_array(([2usize], vec![f16::from_f32(0.5), f16::from_f32(1.5)].into_boxed_slice()))?;
+	///
+	/// let extracted = value.try_extract_tensor::<f16>()?;
+	/// assert_eq!(extracted[[1]], f16::from_f32(1.5));
+	/// # 	Ok(())
+	/// # }
+	/// # #[cfg(not(feature = "half"))]
+	/// # fn main() {}
+	/// ```
+	///
+	/// Note that without the `half` feature, encountering a half-precision tensor will panic.
+	///
+	/// ## Boolean tensors
+	/// `bool` tensors are extracted by reinterpreting their data, which ONNX Runtime stores as one byte per element, as
+	/// Rust `bool`s. This assumes every byte is either 0 or 1, which holds for all tensors produced by ONNX Runtime's
+	/// kernels and for tensors created by `ort`, which validates boolean data passed as raw bytes (see
+	/// [`Tensor::from_bool_bytes`]). To read masks which may contain other values, use [`Tensor::try_extract_as_bool`].
+	///
 	/// # Errors
 	/// May return an error if:
 	/// - This is a [`crate::DynValue`], and the value is not actually a tensor. *(for typed [`Tensor`]s, use the
@@ -43,7 +78,302 @@ impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
 	#[cfg(feature = "ndarray")]
 	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
 	pub fn try_extract_tensor<T: PrimitiveTensorElementType>(&self) -> Result<ndarray::ArrayViewD<'_, T>> {
-		let dtype = self.dtype();
+		let dtype = self.try_dtype()?;
+		match dtype {
+			ValueType::Tensor { ty, dimensions } => {
+				let mem = self.memory_info();
+				if !mem.is_cpu_accessible() {
+					return Err(Error::new(format!("Cannot extract from value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
+				}
+
+				if ty == T::into_tensor_element_type() {
+					Ok(extract_primitive_array(IxDyn(&dimensions.iter().map(|&n| n as usize).collect::<Vec<_>>()), self.ptr())?)
+				} else {
+					Err(Error::new_with_code(
+						ErrorCode::InvalidArgument,
+						format!("Cannot extract Tensor<{}> from Tensor<{}>", T::into_tensor_element_type(), ty)
+					))
+				}
+			}
+			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract a Tensor<{}> from {t}", T::into_tensor_element_type())))
+		}
+	}
+
+	/// Attempt to extract the underlying data of type `T` into an owned `ndarray`, copying it.
+	///
+	/// Unlike [`Tensor::try_extract_tensor`], the returned array does not borrow from this value, so it can outlive it,
+	/// for instance to be moved to another task after the session outputs it came from are dropped. This comes at the
+	/// cost of copying the whole tensor; prefer [`Tensor::try_extract_tensor`] when a view suffices.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let array = {
+	/// 	let value = Value::from_array(([2usize, 2], vec![1_i32, 2, 3, 4].into_boxed_slice()))?;
+	/// 	value.try_extract_owned_tensor::<i32>()?
+	/// };
+	/// assert_eq!(array, ndarray::arr2(&[[1, 2], [3, 4]]).into_dyn());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The provided type `T` does not match the tensor's element type.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn try_extract_owned_tensor<T: PrimitiveTensorElementType + Clone>(&self) -> Result<ndarray::ArrayD<T>> {
+		self.try_extract_tensor::<T>().map(|view| view.to_owned())
+	}
+
+	/// Attempt to extract the subarray left over after pinning some of the tensor's axes to fixed indices.
+	///
+	/// Each entry in `fixed` is a pair of `(axis, index)`. The pinned axes are removed from the result, so e.g. pinning
+	/// the batch and head axes of a `[batch, heads, seq, seq]` attention tensor yields a single `[seq, seq]` plane.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let attention =
+	/// 	ndarray::Array4::<f32>::from_shape_fn((2, 4, 3, 3), |(b, h, i, j)| (b * 1000 + h * 100 + i * 10 + j) as f32);
+	/// let value = Value::from_array(attention.view())?;
+	///
+	/// // batch 0, head 3
+	/// let plane = value.try_extract_plane::<f32>(&[(0, 0), (1, 3)])?;
+	/// assert_eq!(plane.shape(), &[3, 3]);
+	/// assert_eq!(plane[[1, 2]], 312.0);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - Any axis in `fixed` is out of bounds or appears more than once, or any index is out of bounds for its axis.
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The provided type `T` does not match the tensor's element type.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn try_extract_plane<T: PrimitiveTensorElementType + Clone>(&self, fixed: &[(usize, usize)]) -> Result<ndarray::ArrayD<T>> {
+		let mut view = self.try_extract_tensor::<T>()?;
+
+		let mut fixed = fixed.to_vec();
+		// remove axes from the back first so the remaining axis numbers stay valid
+		fixed.sort_unstable_by_key(|&(axis, _)| std::cmp::Reverse(axis));
+		for pair in fixed.windows(2) {
+			if pair[0].0 == pair[1].0 {
+				return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Axis {} was pinned more than once", pair[0].0)));
+			}
+		}
+		for (axis, index) in fixed {
+			if axis >= view.ndim() {
+				return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot pin axis {axis} of a tensor with shape {:?}", self.shape()?)));
+			}
+			if index >= view.len_of(ndarray::Axis(axis)) {
+				return Err(Error::new_with_code(
+					ErrorCode::InvalidArgument,
+					format!("Index {index} is out of bounds for axis {axis} of a tensor with shape {:?}", self.shape()?)
+				));
+			}
+			view = view.index_axis_move(ndarray::Axis(axis), index);
+		}
+		Ok(view.to_owned())
+	}
+
+	/// Attempt to extract the underlying data into an owned `ndarray` of the given `shape`, like NumPy's `reshape`.
+	///
+	/// At most one dimension of `shape` may be `-1`, in which case it is inferred from the number of elements in the
+	/// tensor and the remaining dimensions.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array(([1usize, 6], vec![1_i64, 2, 3, 4, 5, 6].into_boxed_slice()))?;
+	///
+	/// let reshaped = value.try_extract_reshaped::<i64>(&[-1, 2])?;
+	/// assert_eq!(reshaped.shape(), &[3, 2]);
+	/// assert_eq!(reshaped[[2, 0]], 5);
+	///
+	/// assert!(value.try_extract_reshaped::<i64>(&[-1, 4]).is_err());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - `shape` contains more than one `-1`, or any other negative dimension.
+	/// - The product of the dimensions in `shape` overflows a `usize`.
+	/// - The number of elements described by `shape` does not match the number of elements in the tensor, or the
+	///   inferred dimension would not be a whole number.
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The provided type `T` does not match the tensor's element type.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn try_extract_reshaped<T: PrimitiveTensorElementType + Clone>(&self, shape: &[i64]) -> Result<ndarray::ArrayD<T>> {
+		let (original_shape, data) = self.try_extract_raw_tensor::<T>()?;
+
+		let mut inferred = None;
+		let mut known_len = 1usize;
+		for (axis, &dim) in shape.iter().enumerate() {
+			match dim {
+				-1 if inferred.is_some() => {
+					return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Shape {shape:?} may only contain one inferred (-1) dimension")));
+				}
+				-1 => inferred = Some(axis),
+				d if d < 0 => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Shape {shape:?} contains an invalid dimension {d}"))),
+				d => {
+					known_len = usize::try_from(d).ok().and_then(|d| known_len.checked_mul(d)).ok_or_else(|| {
+						Error::new_with_code(ErrorCode::InvalidArgument, format!("Shape {shape:?} describes more elements than can be addressed"))
+					})?;
+				}
+			}
+		}
+
+		let mut dimensions: Vec<usize> = shape.iter().map(|&d| d as usize).collect();
+		match inferred {
+			Some(axis) if known_len != 0 && data.len() % known_len == 0 => dimensions[axis] = data.len() / known_len,
+			None if known_len == data.len() => {}
+			_ => {
+				return Err(Error::new_with_code(
+					ErrorCode::InvalidArgument,
+					format!("Cannot reshape a tensor of shape {original_shape:?} ({} elements) to {shape:?}", data.len())
+				));
+			}
+		}
+
+		Ok(ndarray::Array::from_shape_vec(IxDyn(&dimensions), data.to_vec()).expect("Inferred shape didn't match tensor contents"))
+	}
+
+	/// Attempt to extract the underlying data into an owned `ndarray` with column-major (Fortran) memory layout, for
+	/// interfacing with LAPACK or other Fortran-ordered numerical code.
+	///
+	/// The returned array is logically identical to the tensor - only its memory order differs.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array(([2usize, 3], vec![1_i64, 2, 3, 4, 5, 6].into_boxed_slice()))?;
+	///
+	/// let fortran = value.try_extract_fortran::<i64>()?;
+	/// assert_eq!(fortran[[1, 0]], 4);
+	/// assert!(fortran.t().is_standard_layout());
+	/// assert_eq!(fortran.as_slice_memory_order(), Some(&[1, 4, 2, 5, 3, 6][..]));
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The provided type `T` does not match the tensor's element type.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn try_extract_fortran<T: PrimitiveTensorElementType + Clone>(&self) -> Result<ndarray::ArrayD<T>> {
+		let view = self.try_extract_tensor::<T>()?;
+		// Iterating the transposed view in logical order visits the original elements in column-major order.
+		let data = view.t().iter().cloned().collect();
+		Ok(ndarray::Array::from_shape_vec(view.raw_dim().f(), data).expect("Shape didn't match tensor contents"))
+	}
+
+	/// Attempt to extract the scalar from a tensor of type `T`.
+	///
+	/// ```
+	/// # use std::sync::Arc;
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array(((), vec![3.14_f32]))?;
+	///
+	/// let extracted = value.try_extract_scalar::<f32>()?;
+	/// assert_eq!(extracted, 3.14);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - The tensor is not 0-dimensional.
+	/// - The provided type `T` does not match the tensor's element type.
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor. *(for typed [`Tensor`]s, use the
+	///   infallible [`Tensor::extract_tensor`] instead)*
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn try_extract_scalar<T: PrimitiveTensorElementType + Copy>(&self) -> Result<T> {
+		let dtype = self.try_dtype()?;
+		match dtype {
+			ValueType::Tensor { ty, dimensions } => {
+				let mem = self.memory_info();
+				if !mem.is_cpu_accessible() {
+					return Err(Error::new(format!("Cannot extract from value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
+				}
+
+				if ty == T::into_tensor_element_type() {
+					if !dimensions.is_empty() {
+						return Err(Error::new_with_code(
+							ErrorCode::InvalidArgument,
+							format!("Cannot extract scalar {} from a tensor of dimensionality {}", T::into_tensor_element_type(), dimensions.len())
+						));
+					}
+
+					let mut output_array_ptr: *mut T = ptr::null_mut();
+					let output_array_ptr_ptr: *mut *mut T = &mut output_array_ptr;
+					let output_array_ptr_ptr_void: *mut *mut std::ffi::c_void = output_array_ptr_ptr.cast();
+					ortsys![unsafe GetTensorMutableData(self.ptr(), output_array_ptr_ptr_void)?; nonNull(output_array_ptr)];
+
+					Ok(unsafe { *output_array_ptr })
+				} else {
+					Err(Error::new_with_code(
+						ErrorCode::InvalidArgument,
+						format!("Cannot extract scalar {} from Tensor<{}>", T::into_tensor_element_type(), ty)
+					))
+				}
+			}
+			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract Tensor<{}> from {t}", T::into_tensor_element_type())))
+		}
+	}
+
+	/// Attempt to extract the underlying data of type `T` into a mutable [`ndarray::ArrayViewMut`].
+	///
+	/// The view points directly at the tensor's data (as returned by `GetTensorMutableData`), so this can be used to
+	/// modify a session's output in place, e.g. before feeding it as an input to another session, without copying it.
+	///
+	/// **Be careful when mutating outputs owned by ONNX Runtime.** Modifying an output is only sound as long as no
+	/// other run that writes to the same output has been started, e.g. when the value is bound as an output of an
+	/// [`IoBinding`](crate::IoBinding) that is run again. ONNX Runtime does not synchronize access to the buffer, so a
+	/// concurrent run will race with writes through the view.
+	///
+	/// See also the infallible counterpart, [`Tensor::extract_tensor_mut`], for typed [`Tensor<T>`]s.
+	///
+	/// ```
+	/// # use std::sync::Arc;
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let array = ndarray::Array4::<f32>::ones((1, 16, 16, 3));
+	/// let mut value = Value::from_array(array.view())?;
+	///
+	/// let mut extracted = value.try_extract_tensor_mut::<f32>()?;
+	/// extracted[[0, 0, 0, 1]] = 0.0;
+	///
+	/// let mut array = array.into_dyn();
+	/// assert_ne!(array, extracted);
+	/// array[[0, 0, 0, 1]] = 0.0;
+	/// assert_eq!(array, extracted);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor. *(for typed [`Tensor`]s, use the
+	///   infallible [`Tensor::extract_tensor_mut`] instead)*
+	/// - The provided type `T` does not match the tensor's element type.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	#[doc(alias = "extract_tensor_array_mut")]
+	pub fn try_extract_tensor_mut<T: PrimitiveTensorElementType>(&mut self) -> Result<ndarray::ArrayViewMutD<'_, T>> {
+		let dtype = self.try_dtype()?;
 		match dtype {
 			ValueType::Tensor { ty, dimensions } => {
 				let mem = self.memory_info();
@@ -51,177 +381,958 @@ impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
 					return Err(Error::new(format!("Cannot extract from value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
 				}
 
-				if ty == T::into_tensor_element_type() {
-					Ok(extract_primitive_array(IxDyn(&dimensions.iter().map(|&n| n as usize).collect::<Vec<_>>()), self.ptr())?)
-				} else {
-					Err(Error::new_with_code(
-						ErrorCode::InvalidArgument,
-						format!("Cannot extract Tensor<{}> from Tensor<{}>", T::into_tensor_element_type(), ty)
-					))
+				if ty == T::into_tensor_element_type() {
+					Ok(extract_primitive_array_mut(IxDyn(&dimensions.iter().map(|&n| n as usize).collect::<Vec<_>>()), self.ptr())?)
+				} else {
+					Err(Error::new_with_code(
+						ErrorCode::InvalidArgument,
+						format!("Cannot extract Tensor<{}> from Tensor<{}>", T::into_tensor_element_type(), ty)
+					))
+				}
+			}
+			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract Tensor<{}> from {t}", T::into_tensor_element_type())))
+		}
+	}
+
+	/// Attempt to extract a boolean mask into an owned [`ndarray::Array`] of `bool`s.
+	///
+	/// In addition to real [`TensorElementType::Bool`] tensors, this also accepts [`TensorElementType::Uint8`] and
+	/// [`TensorElementType::Int8`] tensors, which some exporters use to represent masks; any nonzero element maps to
+	/// `true`.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array(([4usize], vec![0_u8, 1, 255, 0].into_boxed_slice()))?;
+	///
+	/// let mask = value.try_extract_as_bool()?;
+	/// assert_eq!(mask, ndarray::arr1(&[false, true, true, false]).into_dyn());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor's element type is not one of `Bool`, `Uint8`, or `Int8`.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn try_extract_as_bool(&self) -> Result<ndarray::ArrayD<bool>> {
+		match self.try_dtype()? {
+			ValueType::Tensor { ty: TensorElementType::Bool, .. } => Ok(self.try_extract_tensor::<bool>()?.to_owned()),
+			ValueType::Tensor { ty: TensorElementType::Uint8, .. } => Ok(self.try_extract_tensor::<u8>()?.mapv(|x| x != 0)),
+			ValueType::Tensor { ty: TensorElementType::Int8, .. } => Ok(self.try_extract_tensor::<i8>()?.mapv(|x| x != 0)),
+			ValueType::Tensor { ty, .. } => Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Cannot extract a boolean mask from Tensor<{ty}>; expected Tensor<bool>, Tensor<u8>, or Tensor<i8>")
+			)),
+			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract a boolean mask from {t}")))
+		}
+	}
+
+	/// Attempt to extract the underlying data into an owned Rust `ndarray`, casting each element from the tensor's
+	/// numeric element type to `T`.
+	///
+	/// Casts are checked, so this fails instead of silently wrapping or truncating - see [`NumericTensorElementType`]
+	/// for the exact rules. Boolean tensors are treated as integers, with `true` mapping to `1`.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array(([3usize], vec![0_i64, 1, 65535].into_boxed_slice()))?;
+	///
+	/// let indices = value.try_extract_as::<i32>()?;
+	/// assert_eq!(indices, ndarray::arr1(&[0, 1, 65535]).into_dyn());
+	/// // 65535 does not fit in an `i16`
+	/// assert!(value.try_extract_as::<i16>().is_err());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is a string tensor.
+	/// - Any element cannot be represented as `T`.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn try_extract_as<T: NumericTensorElementType>(&self) -> Result<ndarray::ArrayD<T>> {
+		let ty = match self.try_dtype()? {
+			ValueType::Tensor { ty, .. } => ty,
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract Tensor<{}> from {t}", T::into_tensor_element_type())))
+		};
+
+		macro_rules! cast {
+			($t:ty, $cast:ident, $via:expr) => {{
+				let view = self.try_extract_tensor::<$t>()?;
+				let data = view
+					.iter()
+					.map(|&x| {
+						let x = $via(x);
+						T::$cast(x).ok_or_else(|| {
+							Error::new_with_code(
+								ErrorCode::InvalidArgument,
+								format!("Element {x} of Tensor<{ty}> cannot be represented as {}", T::into_tensor_element_type())
+							)
+						})
+					})
+					.collect::<Result<Vec<T>>>()?;
+				Ok(ndarray::Array::from_shape_vec(view.raw_dim(), data).expect("Shape extracted from tensor didn't match tensor contents"))
+			}};
+		}
+
+		match ty {
+			TensorElementType::Int8 => cast!(i8, cast_from_i64, i64::from),
+			TensorElementType::Int16 => cast!(i16, cast_from_i64, i64::from),
+			TensorElementType::Int32 => cast!(i32, cast_from_i64, i64::from),
+			TensorElementType::Int64 => cast!(i64, cast_from_i64, i64::from),
+			TensorElementType::Uint8 => cast!(u8, cast_from_u64, u64::from),
+			TensorElementType::Uint16 => cast!(u16, cast_from_u64, u64::from),
+			TensorElementType::Uint32 => cast!(u32, cast_from_u64, u64::from),
+			TensorElementType::Uint64 => cast!(u64, cast_from_u64, u64::from),
+			TensorElementType::Bool => cast!(bool, cast_from_u64, u64::from),
+			TensorElementType::Float32 => cast!(f32, cast_from_f64, f64::from),
+			TensorElementType::Float64 => cast!(f64, cast_from_f64, f64::from),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 => cast!(half::f16, cast_from_f64, half::f16::to_f64),
+			#[cfg(feature = "half")]
+			TensorElementType::Bfloat16 => cast!(half::bf16, cast_from_f64, half::bf16::to_f64),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN => cast!(float8::F8E4M3, cast_from_f64, |x: float8::F8E4M3| x.to_f64()),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E5M2 => cast!(float8::F8E5M2, cast_from_f64, |x: float8::F8E5M2| x.to_f64()),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 | TensorElementType::Complex128 => {
+				Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract Tensor<{}> from Tensor<{ty}>", T::into_tensor_element_type())))
+			}
+			TensorElementType::String => {
+				Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract Tensor<{}> from Tensor<String>", T::into_tensor_element_type())))
+			}
+		}
+	}
+
+	/// Attempt to extract the data of a half-precision (`f16` or `bf16`) tensor into an owned `f32` array, widening
+	/// each element during the copy.
+	///
+	/// This is a faster shortcut for [`Tensor::try_extract_as::<f32>`](Tensor::try_extract_as) specialized for
+	/// half-precision outputs, since widening to `f32` is always exact. To access the data without copying, use
+	/// [`Tensor::try_extract_tensor::<half::f16>`](Tensor::try_extract_tensor) instead.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let data = vec![half::f16::from_f32(0.5), half::f16::from_f32(-2.0)];
+	/// let value = Value::from_array(([2usize], data.into_boxed_slice()))?;
+	/// assert_eq!(value.try_extract_half_as_f32()?, ndarray::arr1(&[0.5_f32, -2.0]).into_dyn());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is not a [`TensorElementType::Float16`] or [`TensorElementType::Bfloat16`] tensor.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[cfg(all(feature = "half", feature = "ndarray"))]
+	#[cfg_attr(docsrs, doc(cfg(all(feature = "half", feature = "ndarray"))))]
+	pub fn try_extract_half_as_f32(&self) -> Result<ndarray::ArrayD<f32>> {
+		match self.try_dtype()? {
+			ValueType::Tensor { ty: TensorElementType::Float16, .. } => Ok(self.try_extract_tensor::<half::f16>()?.mapv(half::f16::to_f32)),
+			ValueType::Tensor { ty: TensorElementType::Bfloat16, .. } => Ok(self.try_extract_tensor::<half::bf16>()?.mapv(half::bf16::to_f32)),
+			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract {t} as f32; expected a half-precision tensor")))
+		}
+	}
+
+	/// Attempt to extract the underlying data into a "raw" view tuple, consisting of the tensor's dimensions and an
+	/// immutable view into its data.
+	///
+	/// This is zero-copy and does not require the `ndarray` feature, making it the preferred way to read tensor outputs
+	/// in builds where `ndarray` is disabled.
+	///
+	/// See also:
+	/// - the mutable counterpart of this function, [`Tensor::try_extract_raw_tensor_mut`].
+	/// - the infallible counterpart, [`Tensor::extract_raw_tensor`], for typed [`Tensor<T>`]s.
+	/// - the alternative function for strings, [`Tensor::try_extract_raw_string_tensor`].
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let array = vec![1_i64, 2, 3, 4, 5];
+	/// let value = Value::from_array(([array.len()], array.clone().into_boxed_slice()))?;
+	///
+	/// let (extracted_shape, extracted_data) = value.try_extract_raw_tensor::<i64>()?;
+	/// assert_eq!(extracted_data, &array);
+	/// assert_eq!(extracted_shape, [5]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor. *(for typed [`Tensor`]s, use the
+	///   infallible [`Tensor::extract_raw_tensor`] instead)*
+	/// - The provided type `T` does not match the tensor's element type.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[doc(alias = "extract_raw_slice")]
+	pub fn try_extract_raw_tensor<T: PrimitiveTensorElementType>(&self) -> Result<(Vec<i64>, &[T])> {
+		let dtype = self.try_dtype()?;
+		match dtype {
+			ValueType::Tensor { ty, dimensions } => {
+				let mem = self.memory_info();
+				if !mem.is_cpu_accessible() {
+					return Err(Error::new(format!("Cannot extract from value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
+				}
+
+				if ty == T::into_tensor_element_type() {
+					let mut output_array_ptr: *mut T = ptr::null_mut();
+					let output_array_ptr_ptr: *mut *mut T = &mut output_array_ptr;
+					let output_array_ptr_ptr_void: *mut *mut std::ffi::c_void = output_array_ptr_ptr.cast();
+					ortsys![unsafe GetTensorMutableData(self.ptr(), output_array_ptr_ptr_void)?; nonNull(output_array_ptr)];
+
+					let len = calculate_tensor_size(&dimensions);
+					Ok((dimensions, unsafe { std::slice::from_raw_parts(output_array_ptr, len) }))
+				} else {
+					Err(Error::new_with_code(
+						ErrorCode::InvalidArgument,
+						format!("Cannot extract Tensor<{}> from Tensor<{}>", T::into_tensor_element_type(), ty)
+					))
+				}
+			}
+			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract Tensor<{}> from {t}", T::into_tensor_element_type())))
+		}
+	}
+
+	/// Attempt to extract the underlying data as an iterator over successive chunks of at most `chunk_len` elements,
+	/// in row-major order. The last chunk will be shorter if the number of elements is not divisible by `chunk_len`.
+	///
+	/// This can be used to process large tensors in blocks (e.g. when writing them to disk) without constructing an
+	/// array view over the entire tensor.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array(([5usize], vec![1_i64, 2, 3, 4, 5].into_boxed_slice()))?;
+	///
+	/// let chunks: Vec<&[i64]> = value.try_extract_chunks::<i64>(2)?.collect();
+	/// assert_eq!(chunks, [&[1, 2][..], &[3, 4], &[5]]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - `chunk_len` is 0.
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The provided type `T` does not match the tensor's element type.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn try_extract_chunks<T: PrimitiveTensorElementType>(&self, chunk_len: usize) -> Result<std::slice::Chunks<'_, T>> {
+		if chunk_len == 0 {
+			return Err(Error::new_with_code(ErrorCode::InvalidArgument, "Chunk length must be greater than 0"));
+		}
+
+		let (_, data) = self.try_extract_raw_tensor::<T>()?;
+		Ok(data.chunks(chunk_len))
+	}
+
+	/// Attempt to append the underlying data of type `T` to the back of a ring buffer, in row-major order, popping the
+	/// oldest elements from the front so that the ring holds at most `capacity` elements.
+	///
+	/// If the tensor itself has more than `capacity` elements, the ring will contain only its last `capacity` elements.
+	///
+	/// ```
+	/// # use std::collections::VecDeque;
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let mut window = VecDeque::new();
+	///
+	/// let frame = Value::from_array(([3usize], vec![1.0_f32, 2.0, 3.0].into_boxed_slice()))?;
+	/// frame.try_extract_append_to(&mut window, 4)?;
+	/// assert_eq!(window, [1.0, 2.0, 3.0]);
+	///
+	/// let frame = Value::from_array(([3usize], vec![4.0_f32, 5.0, 6.0].into_boxed_slice()))?;
+	/// frame.try_extract_append_to(&mut window, 4)?;
+	/// assert_eq!(window, [3.0, 4.0, 5.0, 6.0]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The provided type `T` does not match the tensor's element type.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn try_extract_append_to<T: PrimitiveTensorElementType + Copy>(&self, ring: &mut VecDeque<T>, capacity: usize) -> Result<()> {
+		let (_, data) = self.try_extract_raw_tensor::<T>()?;
+		if data.len() >= capacity {
+			ring.clear();
+			ring.extend(&data[data.len() - capacity..]);
+		} else {
+			let overflow = (ring.len() + data.len()).saturating_sub(capacity);
+			ring.drain(..overflow);
+			ring.extend(data);
+		}
+		Ok(())
+	}
+
+	/// Attempt to copy the underlying data of type `T` into the front of a caller-provided, possibly uninitialized
+	/// buffer, avoiding the cost of zero-initializing a buffer that is about to be overwritten.
+	///
+	/// On success, returns the initialized prefix of `out`, which contains the tensor's data in row-major order. The
+	/// rest of `out` is left untouched.
+	///
+	/// ```
+	/// # use std::mem::MaybeUninit;
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let mut buffer = [MaybeUninit::<f32>::uninit(); 8];
+	///
+	/// let value = Value::from_array(([3usize], vec![1.0_f32, 2.0, 3.0].into_boxed_slice()))?;
+	/// let data = value.try_extract_into_uninit(&mut buffer)?;
+	/// assert_eq!(data, &[1.0, 2.0, 3.0]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - `out` is shorter than the number of elements in the tensor.
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The provided type `T` does not match the tensor's element type.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn try_extract_into_uninit<'o, T: PrimitiveTensorElementType + Copy>(&self, out: &'o mut [MaybeUninit<T>]) -> Result<&'o mut [T]> {
+		let (shape, data) = self.try_extract_raw_tensor::<T>()?;
+		if out.len() < data.len() {
+			return Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Buffer of length {} is too small to hold a tensor of shape {shape:?} ({} elements)", out.len(), data.len())
+			));
+		}
+
+		let out = &mut out[..data.len()];
+		// SAFETY: `MaybeUninit<T>` has the same layout as `T`, the slices are the same length, and they can't overlap since
+		// `out` is a unique borrow. After the copy, every element of `out` is initialized.
+		unsafe {
+			ptr::copy_nonoverlapping(data.as_ptr(), out.as_mut_ptr().cast::<T>(), data.len());
+			Ok(&mut *(out as *mut [MaybeUninit<T>] as *mut [T]))
+		}
+	}
+
+	/// Attempt to extract the underlying data of a rank-2 tensor into a nested `Vec`, with one inner `Vec` per row.
+	///
+	/// This is useful for tabular outputs like count or confusion matrices, where a nested `Vec` is often easier to
+	/// serialize than an `ndarray` array.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array(([2usize, 3], vec![1_i64, 2, 3, 4, 5, 6].into_boxed_slice()))?;
+	/// assert_eq!(value.try_extract_2d_vec::<i64>()?, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is not of rank 2.
+	/// - The provided type `T` does not match the tensor's element type.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn try_extract_2d_vec<T: PrimitiveTensorElementType + Clone>(&self) -> Result<Vec<Vec<T>>> {
+		let (shape, data) = self.try_extract_raw_tensor::<T>()?;
+		let &[rows, cols] = shape.as_slice() else {
+			return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Expected a tensor of rank 2, got a tensor of shape {shape:?}")));
+		};
+		// `chunks` panics with a chunk size of 0
+		if cols == 0 {
+			return Ok(vec![Vec::new(); rows as usize]);
+		}
+		Ok(data.chunks(cols as usize).map(<[T]>::to_vec).collect())
+	}
+
+	/// Attempt to extract the underlying data of a `[height, width, 3]` `u8` tensor (i.e. an interleaved RGB image) as
+	/// a flat, row-major list of pixels, returning `(height, width, pixels)`.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let image = Value::from_array(([1usize, 2, 3], vec![255_u8, 0, 0, 0, 128, 255].into_boxed_slice()))?;
+	///
+	/// let (height, width, pixels) = image.try_extract_pixels_rgb8()?;
+	/// assert_eq!((height, width), (1, 2));
+	/// assert_eq!(pixels, [[255, 0, 0], [0, 128, 255]]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - The tensor is not of shape `[height, width, 3]`.
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is not a `u8` tensor.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn try_extract_pixels_rgb8(&self) -> Result<(usize, usize, Vec<[u8; 3]>)> {
+		let (shape, data) = self.try_extract_raw_tensor::<u8>()?;
+		let &[height, width, 3] = shape.as_slice() else {
+			return Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Expected a tensor of shape [height, width, 3], got a tensor of shape {shape:?}")
+			));
+		};
+		let pixels = data.chunks_exact(3).map(|p| [p[0], p[1], p[2]]).collect();
+		Ok((height as usize, width as usize, pixels))
+	}
+
+	/// Attempt to extract the underlying data of a boolean tensor as a run-length encoding, consisting of the tensor's
+	/// dimensions and a list of `(value, run length)` pairs over its data in row-major order.
+	///
+	/// This is a compact representation for large, sparse masks (like those output by segmentation models) that
+	/// avoids creating a dense intermediate array.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let mask = Value::from_array(([2usize, 3], vec![false, false, true, true, true, false].into_boxed_slice()))?;
+	///
+	/// let (shape, runs) = mask.try_extract_rle()?;
+	/// assert_eq!(shape, [2, 3]);
+	/// assert_eq!(runs, [(false, 2), (true, 3), (false, 1)]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is not a boolean tensor.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[allow(clippy::type_complexity)]
+	pub fn try_extract_rle(&self) -> Result<(Vec<i64>, Vec<(bool, usize)>)> {
+		let (shape, data) = self.try_extract_raw_tensor::<bool>()?;
+		let mut runs: Vec<(bool, usize)> = Vec::new();
+		for &x in data {
+			match runs.last_mut() {
+				Some((value, len)) if *value == x => *len += 1,
+				_ => runs.push((x, 1))
+			}
+		}
+		Ok((shape, runs))
+	}
+
+	/// Attempt to copy the underlying data of type `T` into an [`aligned_vec::AVec`] aligned to at least `align` bytes,
+	/// so that it can be passed directly to SIMD kernels requiring over-aligned loads.
+	///
+	/// The actual alignment may be greater than `align` if `T` itself requires a greater alignment.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array(([4usize], vec![1.0_f32, 2.0, 3.0, 4.0].into_boxed_slice()))?;
+	///
+	/// let data = value.try_extract_aligned_vec::<f32>(32)?;
+	/// assert_eq!(data.as_ptr() as usize % 32, 0);
+	/// assert_eq!(&data[..], &[1.0, 2.0, 3.0, 4.0]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - `align` is not a power of two.
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The provided type `T` does not match the tensor's element type.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[cfg(feature = "aligned-vec")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "aligned-vec")))]
+	pub fn try_extract_aligned_vec<T: PrimitiveTensorElementType + Clone>(&self, align: usize) -> Result<aligned_vec::AVec<T, aligned_vec::RuntimeAlign>> {
+		if !align.is_power_of_two() {
+			return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Alignment must be a power of two, got {align}")));
+		}
+
+		let (_, data) = self.try_extract_raw_tensor::<T>()?;
+		Ok(aligned_vec::AVec::from_slice(align, data))
+	}
+
+	/// Counts the number of NaN or infinite elements in this tensor, which can be used to cheaply detect numerically
+	/// unstable outputs.
+	///
+	/// Integer and boolean tensors can't represent non-finite values, so this always returns `0` for them.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array(([4usize], vec![1.0_f32, f32::NAN, f32::INFINITY, -0.5].into_boxed_slice()))?;
+	/// assert_eq!(value.count_non_finite()?, 2);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is a string tensor.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn count_non_finite(&self) -> Result<usize> {
+		let ty = match self.try_dtype()? {
+			ValueType::Tensor { ty, .. } => ty,
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot count non-finite elements of {t}")))
+		};
+		Ok(match ty {
+			TensorElementType::Float32 => self.try_extract_raw_tensor::<f32>()?.1.iter().filter(|x| !x.is_finite()).count(),
+			TensorElementType::Float64 => self.try_extract_raw_tensor::<f64>()?.1.iter().filter(|x| !x.is_finite()).count(),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 => self.try_extract_raw_tensor::<half::f16>()?.1.iter().filter(|x| !x.is_finite()).count(),
+			#[cfg(feature = "half")]
+			TensorElementType::Bfloat16 => self.try_extract_raw_tensor::<half::bf16>()?.1.iter().filter(|x| !x.is_finite()).count(),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN => self
+				.try_extract_raw_tensor::<float8::F8E4M3>()?
+				.1
+				.iter()
+				.filter(|x| !x.is_finite())
+				.count(),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E5M2 => self
+				.try_extract_raw_tensor::<float8::F8E5M2>()?
+				.1
+				.iter()
+				.filter(|x| !x.is_finite())
+				.count(),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 => self
+				.try_extract_raw_tensor::<num_complex::Complex<f32>>()?
+				.1
+				.iter()
+				.filter(|x| !x.is_finite())
+				.count(),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex128 => self
+				.try_extract_raw_tensor::<num_complex::Complex<f64>>()?
+				.1
+				.iter()
+				.filter(|x| !x.is_finite())
+				.count(),
+			TensorElementType::String => {
+				return Err(Error::new_with_code(ErrorCode::InvalidArgument, "Cannot count non-finite elements of Tensor<String>"));
+			}
+			_ => {
+				let mem = self.memory_info();
+				if !mem.is_cpu_accessible() {
+					return Err(Error::new(format!("Cannot extract from value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
+				}
+				0
+			}
+		})
+	}
+
+	/// Returns `true` if every element of this tensor is zero, stopping at the first nonzero element.
+	///
+	/// Floating point tensors compare by value, so `-0.0` counts as zero and NaN does not. Boolean tensors are all-zero
+	/// if every element is `false`, and string tensors are all-zero if every element is an empty string.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let mask = Value::from_array(([4usize], vec![0.0_f32, -0.0, 0.0, 0.0].into_boxed_slice()))?;
+	/// assert!(mask.is_all_zero()?);
+	///
+	/// let mask = Value::from_array(([4usize], vec![0_i64, 0, 1, 0].into_boxed_slice()))?;
+	/// assert!(!mask.is_all_zero()?);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn is_all_zero(&self) -> Result<bool> {
+		let ty = match self.try_dtype()? {
+			ValueType::Tensor { ty, .. } => ty,
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot check whether {t} is all-zero; expected a tensor")))
+		};
+
+		macro_rules! all_zero {
+			($t:ty, $zero:expr) => {
+				self.try_extract_raw_tensor::<$t>()?.1.iter().all(|&x| x == $zero)
+			};
+		}
+
+		Ok(match ty {
+			TensorElementType::Float32 => all_zero!(f32, 0.0),
+			TensorElementType::Float64 => all_zero!(f64, 0.0),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 => all_zero!(half::f16, half::f16::ZERO),
+			#[cfg(feature = "half")]
+			TensorElementType::Bfloat16 => all_zero!(half::bf16, half::bf16::ZERO),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN => all_zero!(float8::F8E4M3, float8::F8E4M3::ZERO),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E5M2 => all_zero!(float8::F8E5M2, float8::F8E5M2::ZERO),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 => all_zero!(num_complex::Complex<f32>, num_complex::Complex::new(0.0, 0.0)),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex128 => all_zero!(num_complex::Complex<f64>, num_complex::Complex::new(0.0, 0.0)),
+			TensorElementType::Int8 => all_zero!(i8, 0),
+			TensorElementType::Int16 => all_zero!(i16, 0),
+			TensorElementType::Int32 => all_zero!(i32, 0),
+			TensorElementType::Int64 => all_zero!(i64, 0),
+			TensorElementType::Uint8 => all_zero!(u8, 0),
+			TensorElementType::Uint16 => all_zero!(u16, 0),
+			TensorElementType::Uint32 => all_zero!(u32, 0),
+			TensorElementType::Uint64 => all_zero!(u64, 0),
+			TensorElementType::Bool => all_zero!(bool, false),
+			TensorElementType::String => {
+				let mem = self.memory_info();
+				if !mem.is_cpu_accessible() {
+					return Err(Error::new(format!("Cannot extract from value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
+				}
+				// every string is empty iff there is no string data at all
+				self.byte_size()? == 0
+			}
+		})
+	}
+
+	/// Returns the minimum and maximum elements of this numeric tensor as `f64`s, computed in a single pass over its
+	/// data. NaN elements are ignored.
+	///
+	/// Note that 64-bit integers larger than 2<sup>53</sup> in magnitude are rounded to the nearest representable
+	/// `f64`.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let value = Value::from_array(([4usize], vec![0.5_f32, f32::NAN, -2.0, 3.25].into_boxed_slice()))?;
+	/// assert_eq!(value.min_max()?, (-2.0, 3.25));
+	///
+	/// let value = Value::from_array(([3usize], vec![7_u8, 255, 0].into_boxed_slice()))?;
+	/// assert_eq!(value.min_max()?, (0.0, 255.0));
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is a boolean or string tensor.
+	/// - The tensor is empty, or all of its elements are NaN.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn min_max(&self) -> Result<(f64, f64)> {
+		let ty = match self.try_dtype()? {
+			ValueType::Tensor { ty, .. } => ty,
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot get the range of {t}; expected a tensor")))
+		};
+
+		macro_rules! min_max {
+			($t:ty, $conv:expr) => {
+				self.try_extract_raw_tensor::<$t>()?
+					.1
+					.iter()
+					.map(|&x| $conv(x))
+					.filter(|x: &f64| !x.is_nan())
+					.fold(None, |range, x| match range {
+						Some((min, max)) => Some((x.min(min), x.max(max))),
+						None => Some((x, x))
+					})
+			};
+		}
+
+		let range = match ty {
+			TensorElementType::Float32 => min_max!(f32, f64::from),
+			TensorElementType::Float64 => min_max!(f64, f64::from),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 => min_max!(half::f16, half::f16::to_f64),
+			#[cfg(feature = "half")]
+			TensorElementType::Bfloat16 => min_max!(half::bf16, half::bf16::to_f64),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN => min_max!(float8::F8E4M3, |x: float8::F8E4M3| x.to_f64()),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E5M2 => min_max!(float8::F8E5M2, |x: float8::F8E5M2| x.to_f64()),
+			TensorElementType::Int8 => min_max!(i8, f64::from),
+			TensorElementType::Int16 => min_max!(i16, f64::from),
+			TensorElementType::Int32 => min_max!(i32, f64::from),
+			TensorElementType::Int64 => min_max!(i64, |x| x as f64),
+			TensorElementType::Uint8 => min_max!(u8, f64::from),
+			TensorElementType::Uint16 => min_max!(u16, f64::from),
+			TensorElementType::Uint32 => min_max!(u32, f64::from),
+			TensorElementType::Uint64 => min_max!(u64, |x| x as f64),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 | TensorElementType::Complex128 => {
+				return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot get the range of Tensor<{ty}>; complex numbers are unordered")));
+			}
+			TensorElementType::Bool | TensorElementType::String => {
+				return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot get the range of Tensor<{ty}>; expected a numeric tensor")));
+			}
+		};
+		range.ok_or_else(|| Error::new("Cannot get the range of a tensor with no non-NaN elements"))
+	}
+
+	/// Returns the index of the largest element along the last axis for each sample of a batched floating-point tensor,
+	/// i.e. the predicted class of each row of an `[N, C]` tensor of logits or probabilities. NaN elements are ignored;
+	/// if multiple elements are tied for the largest, the first is chosen.
+	///
+	/// Since softmax preserves order, logits do not need to be normalized beforehand. Tensors with more than 2
+	/// dimensions are treated as a flattened batch of rows, e.g. a `[N, T, C]` tensor produces `N * T` class indices.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let logits = Value::from_array(([3usize, 3], vec![0.1_f32, 2.5, -1.0, 4.0, 0.0, 3.9, -2.0, -3.0, -1.5]))?;
+	/// assert_eq!(logits.predict_classes()?, vec![1, 0, 2]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is not a floating-point tensor.
+	/// - The tensor is 0-dimensional, its last dimension is 0, or any row consists entirely of NaN elements.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn predict_classes(&self) -> Result<Vec<usize>> {
+		let (ty, shape) = match self.try_dtype()? {
+			ValueType::Tensor { ty, dimensions } => (ty, dimensions),
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot predict classes from {t}; expected a tensor")))
+		};
+		let num_classes = match shape.last() {
+			Some(&c) if c > 0 => c as usize,
+			_ => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot predict classes from a tensor of shape {shape:?}")))
+		};
+
+		macro_rules! predict_classes {
+			($t:ty, $conv:expr) => {
+				self.try_extract_raw_tensor::<$t>()?
+					.1
+					.chunks(num_classes)
+					.map(|row| {
+						row.iter()
+							.map(|&x| $conv(x))
+							.enumerate()
+							.filter(|(_, x): &(usize, f64)| !x.is_nan())
+							.fold(None, |best: Option<(usize, f64)>, (i, x)| match best {
+								Some((_, max)) if x <= max => best,
+								_ => Some((i, x))
+							})
+							.map(|(i, _)| i)
+							.ok_or_else(|| Error::new("Cannot predict the class of a row with no non-NaN elements"))
+					})
+					.collect()
+			};
+		}
+
+		match ty {
+			TensorElementType::Float32 => predict_classes!(f32, f64::from),
+			TensorElementType::Float64 => predict_classes!(f64, f64::from),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 => predict_classes!(half::f16, half::f16::to_f64),
+			#[cfg(feature = "half")]
+			TensorElementType::Bfloat16 => predict_classes!(half::bf16, half::bf16::to_f64),
+			_ => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot predict classes from Tensor<{ty}>; expected a floating-point tensor")))
+		}
+	}
+
+	/// Returns the most frequent value of this integer tensor and the number of times it occurs, e.g. the most common
+	/// predicted class of a batch or segmentation map. If multiple values are equally frequent, the smallest is chosen.
+	///
+	/// ```
+	/// # use ort::{Session, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let labels = Value::from_array(([2usize, 4], vec![3_i64, 1, 3, 0, 1, 3, 2, 1].into_boxed_slice()))?;
+	/// assert_eq!(labels.mode()?, (1, 3));
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is not an integer tensor, or is empty.
+	/// - The most frequent value of a `u64` tensor does not fit in an `i64`.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn mode(&self) -> Result<(i64, usize)> {
+		let ty = match self.try_dtype()? {
+			ValueType::Tensor { ty, .. } => ty,
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot get the mode of {t}; expected a tensor")))
+		};
+
+		macro_rules! mode {
+			($t:ty) => {{
+				let mut counts: HashMap<$t, usize> = HashMap::new();
+				for &x in self.try_extract_raw_tensor::<$t>()?.1 {
+					*counts.entry(x).or_default() += 1;
 				}
-			}
-			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract a Tensor<{}> from {t}", T::into_tensor_element_type())))
+				counts
+					.into_iter()
+					.max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then(b.cmp(a)))
+			}};
 		}
+
+		let mode = match ty {
+			TensorElementType::Int8 => mode!(i8).map(|(x, n)| (i64::from(x), n)),
+			TensorElementType::Int16 => mode!(i16).map(|(x, n)| (i64::from(x), n)),
+			TensorElementType::Int32 => mode!(i32).map(|(x, n)| (i64::from(x), n)),
+			TensorElementType::Int64 => mode!(i64),
+			TensorElementType::Uint8 => mode!(u8).map(|(x, n)| (i64::from(x), n)),
+			TensorElementType::Uint16 => mode!(u16).map(|(x, n)| (i64::from(x), n)),
+			TensorElementType::Uint32 => mode!(u32).map(|(x, n)| (i64::from(x), n)),
+			TensorElementType::Uint64 => match mode!(u64) {
+				Some((x, n)) => Some((i64::try_from(x).map_err(|_| Error::new(format!("Mode {x} of Tensor<u64> does not fit in an i64")))?, n)),
+				None => None
+			},
+			_ => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot get the mode of Tensor<{ty}>; expected an integer tensor")))
+		};
+		mode.ok_or_else(|| Error::new("Cannot get the mode of an empty tensor"))
 	}
 
-	/// Attempt to extract the scalar from a tensor of type `T`.
+	/// Returns the `q`-th quantile of the elements of this numeric tensor as an `f64`, linearly interpolating between
+	/// the two nearest elements when the quantile falls between them. `q = 0.5` gives the median, while `q = 0.0` and
+	/// `q = 1.0` give the minimum and maximum respectively. NaN elements are ignored.
+	///
+	/// The quantile is found by selection rather than by fully sorting the tensor's data, so this runs in linear time.
 	///
 	/// ```
-	/// # use std::sync::Arc;
 	/// # use ort::{Session, Value};
 	/// # fn main() -> ort::Result<()> {
-	/// let value = Value::from_array(((), vec![3.14_f32]))?;
+	/// let value = Value::from_array(([5usize], vec![3.0_f32, 1.0, 4.0, 1.0, 5.0].into_boxed_slice()))?;
+	/// assert_eq!(value.quantile(0.5)?, 3.0);
+	/// assert_eq!(value.quantile(0.75)?, 4.0);
 	///
-	/// let extracted = value.try_extract_scalar::<f32>()?;
-	/// assert_eq!(extracted, 3.14);
+	/// let value = Value::from_array(([4usize], vec![10_i32, 40, 20, 30].into_boxed_slice()))?;
+	/// assert_eq!(value.quantile(0.5)?, 25.0);
 	/// # 	Ok(())
 	/// # }
 	/// ```
 	///
 	/// # Errors
 	/// May return an error if:
-	/// - The tensor is not 0-dimensional.
-	/// - The provided type `T` does not match the tensor's element type.
-	/// - This is a [`crate::DynValue`], and the value is not actually a tensor. *(for typed [`Tensor`]s, use the
-	///   infallible [`Tensor::extract_tensor`] instead)*
+	/// - `q` is not in the range `0.0..=1.0`.
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is a boolean, string, or complex tensor.
+	/// - The tensor is empty, or all of its elements are NaN.
 	/// - The tensor's data is not allocated in CPU memory.
-	pub fn try_extract_scalar<T: PrimitiveTensorElementType + Copy>(&self) -> Result<T> {
-		let dtype = self.dtype();
-		match dtype {
-			ValueType::Tensor { ty, dimensions } => {
-				let mem = self.memory_info();
-				if !mem.is_cpu_accessible() {
-					return Err(Error::new(format!("Cannot extract from value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
-				}
-
-				if ty == T::into_tensor_element_type() {
-					if !dimensions.is_empty() {
-						return Err(Error::new_with_code(
-							ErrorCode::InvalidArgument,
-							format!("Cannot extract scalar {} from a tensor of dimensionality {}", T::into_tensor_element_type(), dimensions.len())
-						));
-					}
+	pub fn quantile(&self, q: f64) -> Result<f64> {
+		if !(0.0..=1.0).contains(&q) {
+			return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Quantile {q} is out of range; expected a value between 0 and 1")));
+		}
 
-					let mut output_array_ptr: *mut T = ptr::null_mut();
-					let output_array_ptr_ptr: *mut *mut T = &mut output_array_ptr;
-					let output_array_ptr_ptr_void: *mut *mut std::ffi::c_void = output_array_ptr_ptr.cast();
-					ortsys![unsafe GetTensorMutableData(self.ptr(), output_array_ptr_ptr_void)?; nonNull(output_array_ptr)];
+		let (_, mut data) = self.to_f64_vec()?;
+		data.retain(|x| !x.is_nan());
+		if data.is_empty() {
+			return Err(Error::new("Cannot get a quantile of a tensor with no non-NaN elements"));
+		}
 
-					Ok(unsafe { *output_array_ptr })
-				} else {
-					Err(Error::new_with_code(
-						ErrorCode::InvalidArgument,
-						format!("Cannot extract scalar {} from Tensor<{}>", T::into_tensor_element_type(), ty)
-					))
-				}
-			}
-			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract Tensor<{}> from {t}", T::into_tensor_element_type())))
+		let pos = q * (data.len() - 1) as f64;
+		let lower_idx = pos.floor() as usize;
+		let (_, &mut lower, upper) = data.select_nth_unstable_by(lower_idx, f64::total_cmp);
+		if lower_idx as f64 == pos {
+			return Ok(lower);
 		}
+		// the element following `lower` in sorted order is the smallest of those after it
+		let upper = upper.iter().copied().fold(f64::INFINITY, f64::min);
+		Ok(lower + (upper - lower) * (pos - lower_idx as f64))
 	}
 
-	/// Attempt to extract the underlying data of type `T` into a mutable read-only [`ndarray::ArrayViewMut`].
+	/// Extracts this numeric tensor as an `f32` array and divides each vector along `axis` by its L2 norm, e.g. to
+	/// normalize the `[N, D]` output of an embedding model along axis `1` so that cosine similarity becomes a dot
+	/// product.
 	///
-	/// See also the infallible counterpart, [`Tensor::extract_tensor_mut`], for typed [`Tensor<T>`]s.
+	/// Elements are cast to `f32` as in [`Tensor::try_extract_as`]. Norms are computed in `f64` and clamped to a
+	/// minimum of `1e-12` before dividing, so vectors of all zeros are left as zeros rather than becoming NaN.
 	///
 	/// ```
-	/// # use std::sync::Arc;
 	/// # use ort::{Session, Value};
 	/// # fn main() -> ort::Result<()> {
-	/// let array = ndarray::Array4::<f32>::ones((1, 16, 16, 3));
-	/// let mut value = Value::from_array(array.view())?;
-	///
-	/// let mut extracted = value.try_extract_tensor_mut::<f32>()?;
-	/// extracted[[0, 0, 0, 1]] = 0.0;
-	///
-	/// let mut array = array.into_dyn();
-	/// assert_ne!(array, extracted);
-	/// array[[0, 0, 0, 1]] = 0.0;
-	/// assert_eq!(array, extracted);
+	/// let embeddings = Value::from_array(([2usize, 2], vec![3.0_f32, 4.0, 0.0, 0.0].into_boxed_slice()))?;
+	/// let normalized = embeddings.l2_normalize(1)?;
+	/// assert_eq!(normalized, ndarray::arr2(&[[0.6, 0.8], [0.0, 0.0]]).into_dyn());
 	/// # 	Ok(())
 	/// # }
 	/// ```
 	///
 	/// # Errors
 	/// May return an error if:
-	/// - This is a [`crate::DynValue`], and the value is not actually a tensor. *(for typed [`Tensor`]s, use the
-	///   infallible [`Tensor::extract_tensor_mut`] instead)*
-	/// - The provided type `T` does not match the tensor's element type.
+	/// - `axis` is out of bounds for the tensor's shape.
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is a string or complex tensor, or any element cannot be represented as an `f32`.
+	/// - The tensor's data is not allocated in CPU memory.
 	#[cfg(feature = "ndarray")]
 	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
-	pub fn try_extract_tensor_mut<T: PrimitiveTensorElementType>(&mut self) -> Result<ndarray::ArrayViewMutD<'_, T>> {
-		let dtype = self.dtype();
-		match dtype {
-			ValueType::Tensor { ty, dimensions } => {
-				let mem = self.memory_info();
-				if !mem.is_cpu_accessible() {
-					return Err(Error::new(format!("Cannot extract from value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
-				}
+	pub fn l2_normalize(&self, axis: usize) -> Result<ndarray::ArrayD<f32>> {
+		let mut array = self.try_extract_as::<f32>()?;
+		if axis >= array.ndim() {
+			return Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Cannot normalize along axis {axis} of a tensor with {} dimensions", array.ndim())
+			));
+		}
 
-				if ty == T::into_tensor_element_type() {
-					Ok(extract_primitive_array_mut(IxDyn(&dimensions.iter().map(|&n| n as usize).collect::<Vec<_>>()), self.ptr())?)
-				} else {
-					Err(Error::new_with_code(
-						ErrorCode::InvalidArgument,
-						format!("Cannot extract Tensor<{}> from Tensor<{}>", T::into_tensor_element_type(), ty)
-					))
-				}
-			}
-			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract Tensor<{}> from {t}", T::into_tensor_element_type())))
+		for mut lane in array.lanes_mut(Axis(axis)) {
+			let norm = lane.iter().map(|&x| f64::from(x).powi(2)).sum::<f64>().sqrt().max(1e-12);
+			lane.mapv_inplace(|x| (f64::from(x) / norm) as f32);
 		}
+		Ok(array)
 	}
 
-	/// Attempt to extract the underlying data into a "raw" view tuple, consisting of the tensor's dimensions and an
-	/// immutable view into its data.
+	/// Computes the cosine similarity between each pair of corresponding vectors along `axis` in this tensor and
+	/// `other`, e.g. between the `[N, D]` outputs of two embedding models along axis `1`. The returned array has the
+	/// shape of the inputs with `axis` removed.
 	///
-	/// See also:
-	/// - the mutable counterpart of this function, [`Tensor::try_extract_raw_tensor_mut`].
-	/// - the infallible counterpart, [`Tensor::extract_raw_tensor`], for typed [`Tensor<T>`]s.
-	/// - the alternative function for strings, [`Tensor::try_extract_raw_string_tensor`].
+	/// Elements are cast to `f32` as in [`Tensor::try_extract_as`], and the tensors may have different element types.
+	/// Dot products and norms are computed in `f64`. If either vector of a pair has a norm of zero, their similarity is
+	/// `0` rather than NaN.
 	///
 	/// ```
 	/// # use ort::{Session, Value};
 	/// # fn main() -> ort::Result<()> {
-	/// let array = vec![1_i64, 2, 3, 4, 5];
-	/// let value = Value::from_array(([array.len()], array.clone().into_boxed_slice()))?;
+	/// let a = Value::from_array(([3usize, 2], vec![1.0_f32, 0.0, 1.0, 1.0, 0.0, 0.0].into_boxed_slice()))?;
+	/// let b = Value::from_array(([3usize, 2], vec![2.0_f32, 0.0, -1.0, -1.0, 1.0, 0.0].into_boxed_slice()))?;
 	///
-	/// let (extracted_shape, extracted_data) = value.try_extract_raw_tensor::<i64>()?;
-	/// assert_eq!(extracted_data, &array);
-	/// assert_eq!(extracted_shape, [5]);
+	/// let similarity = a.cosine_similarity(&b, 1)?;
+	/// assert_eq!(similarity, ndarray::arr1(&[1.0, -1.0, 0.0]).into_dyn());
 	/// # 	Ok(())
 	/// # }
 	/// ```
 	///
 	/// # Errors
 	/// May return an error if:
-	/// - This is a [`crate::DynValue`], and the value is not actually a tensor. *(for typed [`Tensor`]s, use the
-	///   infallible [`Tensor::extract_raw_tensor`] instead)*
-	/// - The provided type `T` does not match the tensor's element type.
-	pub fn try_extract_raw_tensor<T: PrimitiveTensorElementType>(&self) -> Result<(Vec<i64>, &[T])> {
-		let dtype = self.dtype();
-		match dtype {
-			ValueType::Tensor { ty, dimensions } => {
-				let mem = self.memory_info();
-				if !mem.is_cpu_accessible() {
-					return Err(Error::new(format!("Cannot extract from value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
-				}
-
-				if ty == T::into_tensor_element_type() {
-					let mut output_array_ptr: *mut T = ptr::null_mut();
-					let output_array_ptr_ptr: *mut *mut T = &mut output_array_ptr;
-					let output_array_ptr_ptr_void: *mut *mut std::ffi::c_void = output_array_ptr_ptr.cast();
-					ortsys![unsafe GetTensorMutableData(self.ptr(), output_array_ptr_ptr_void)?; nonNull(output_array_ptr)];
+	/// - The tensors have different shapes.
+	/// - `axis` is out of bounds for the tensors' shape.
+	/// - Either value is not a tensor, or is a string or complex tensor, or any element cannot be represented as an
+	///   `f32`.
+	/// - Either tensor's data is not allocated in CPU memory.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn cosine_similarity<OtherType: TensorValueTypeMarker + ?Sized>(&self, other: &Value<OtherType>, axis: usize) -> Result<ndarray::ArrayD<f32>> {
+		let a = self.try_extract_as::<f32>()?;
+		let b = other.try_extract_as::<f32>()?;
+		if a.shape() != b.shape() {
+			return Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Cannot compare tensors of different shapes {:?} and {:?}", a.shape(), b.shape())
+			));
+		}
+		if axis >= a.ndim() {
+			return Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Cannot compute similarity along axis {axis} of a tensor with {} dimensions", a.ndim())
+			));
+		}
 
-					let len = calculate_tensor_size(&dimensions);
-					Ok((dimensions, unsafe { std::slice::from_raw_parts(output_array_ptr, len) }))
-				} else {
-					Err(Error::new_with_code(
-						ErrorCode::InvalidArgument,
-						format!("Cannot extract Tensor<{}> from Tensor<{}>", T::into_tensor_element_type(), ty)
-					))
-				}
+		Ok(ndarray::Zip::from(a.lanes(Axis(axis))).and(b.lanes(Axis(axis))).map_collect(|a, b| {
+			let (mut dot, mut norm_a, mut norm_b) = (0.0, 0.0, 0.0);
+			for (&x, &y) in a.iter().zip(b.iter()) {
+				let (x, y) = (f64::from(x), f64::from(y));
+				dot += x * y;
+				norm_a += x * x;
+				norm_b += y * y;
 			}
-			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract Tensor<{}> from {t}", T::into_tensor_element_type())))
-		}
+			if norm_a == 0.0 || norm_b == 0.0 {
+				0.0
+			} else {
+				(dot / (norm_a.sqrt() * norm_b.sqrt())) as f32
+			}
+		}))
 	}
 
 	/// Attempt to extract the underlying data into a "raw" view tuple, consisting of the tensor's dimensions and a
@@ -248,7 +1359,7 @@ impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
 	///   infallible [`Tensor::extract_raw_tensor_mut`] instead)*
 	/// - The provided type `T` does not match the tensor's element type.
 	pub fn try_extract_raw_tensor_mut<T: PrimitiveTensorElementType>(&mut self) -> Result<(Vec<i64>, &mut [T])> {
-		let dtype = self.dtype();
+		let dtype = self.try_dtype()?;
 		match dtype {
 			ValueType::Tensor { ty, dimensions } => {
 				let mem = self.memory_info();
@@ -291,55 +1402,87 @@ impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
 	#[cfg(feature = "ndarray")]
 	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
 	pub fn try_extract_string_tensor(&self) -> Result<ndarray::ArrayD<String>> {
-		let dtype = self.dtype();
-		match dtype {
-			ValueType::Tensor { ty, dimensions } => {
-				let mem = self.memory_info();
-				if !mem.is_cpu_accessible() {
-					return Err(Error::new(format!("Cannot extract from value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
-				}
-
-				if ty == TensorElementType::String {
-					let len = calculate_tensor_size(&dimensions);
-
-					// Total length of string data, not including \0 suffix
-					let mut total_length: ort_sys::size_t = 0;
-					ortsys![unsafe GetStringTensorDataLength(self.ptr(), &mut total_length)?];
-
-					// In the JNI impl of this, tensor_element_len was included in addition to total_length,
-					// but that seems contrary to the docs of GetStringTensorDataLength, and those extra bytes
-					// don't seem to be written to in practice either.
-					// If the string data actually did go farther, it would panic below when using the offset
-					// data to get slices for each string.
-					let mut string_contents = vec![0u8; total_length as _];
-					// one extra slot so that the total length can go in the last one, making all per-string
-					// length calculations easy
-					let mut offsets = vec![0; (len + 1) as _];
-
-					ortsys![unsafe GetStringTensorContent(self.ptr(), string_contents.as_mut_ptr().cast(), total_length, offsets.as_mut_ptr(), len as _)?];
-
-					// final offset = overall length so that per-string length calculations work for the last string
-					debug_assert_eq!(0, offsets[len]);
-					offsets[len] = total_length;
+		let (dimensions, strings) = self.extract_strings(str::to_owned)?;
+		Ok(ndarray::Array::from_shape_vec(IxDyn(&dimensions.iter().map(|&n| n as usize).collect::<Vec<_>>()), strings)
+			.expect("Shape extracted from tensor didn't match tensor contents"))
+	}
 
-					let strings = offsets
-						// offsets has 1 extra offset past the end so that all windows work
-						.windows(2)
-						.map(|w| {
-							let slice = &string_contents[w[0] as _..w[1] as _];
-							String::from_utf8(slice.into())
-						})
-						.collect::<Result<Vec<String>, FromUtf8Error>>()
-						.map_err(Error::wrap)?;
+	/// Attempt to extract the underlying string data into a Rust `ndarray` of shared [`Arc<str>`]s.
+	///
+	/// Each string is decoded once into its own reference-counted allocation, so the strings can be cheaply cloned
+	/// and shared across threads or retained in other data structures, unlike the `String`s returned by
+	/// [`Tensor::try_extract_string_tensor`].
+	///
+	/// ```
+	/// # use std::sync::Arc;
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_string_array(ndarray::Array1::from_vec(vec!["cat", "dog"]))?;
+	///
+	/// let labels = tensor.try_extract_shared_string_tensor()?;
+	/// let label: Arc<str> = Arc::clone(&labels[[1]]);
+	/// assert_eq!(&*label, "dog");
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn try_extract_shared_string_tensor(&self) -> Result<ndarray::ArrayD<Arc<str>>> {
+		let (dimensions, strings) = self.extract_strings(|s| Arc::from(s))?;
+		Ok(ndarray::Array::from_shape_vec(IxDyn(&dimensions.iter().map(|&n| n as usize).collect::<Vec<_>>()), strings)
+			.expect("Shape extracted from tensor didn't match tensor contents"))
+	}
 
-					Ok(ndarray::Array::from_shape_vec(IxDyn(&dimensions.iter().map(|&n| n as usize).collect::<Vec<_>>()), strings)
-						.expect("Shape extracted from tensor didn't match tensor contents"))
-				} else {
-					Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract Tensor<String> from Tensor<{ty}>")))
-				}
+	/// Attempt to extract the underlying string data as an array of interned indices into a dictionary of the tensor's
+	/// unique strings, in the order in which they first appear.
+	///
+	/// Each unique string is only allocated once, so this uses much less memory than
+	/// [`Tensor::try_extract_string_tensor`] for tensors dominated by a small set of repeated values, like categorical
+	/// labels. Indices can also be compared much faster than strings.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_string_array(ndarray::Array1::from_vec(vec!["cat", "dog", "cat", "cat"]))?;
+	///
+	/// let (indices, dictionary) = tensor.try_extract_interned_string_tensor()?;
+	/// assert_eq!(dictionary, ["cat", "dog"]);
+	/// assert_eq!(indices, ndarray::arr1(&[0, 1, 0, 0]).into_dyn());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a string tensor.
+	/// - The tensor's data is not allocated in CPU memory.
+	/// - A string is not valid UTF-8.
+	/// - The tensor has more than `u32::MAX` unique strings.
+	#[cfg(feature = "ndarray")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+	pub fn try_extract_interned_string_tensor(&self) -> Result<(ndarray::ArrayD<u32>, Vec<String>)> {
+		let mut lookup: HashMap<String, u32> = HashMap::new();
+		let mut dictionary: Vec<String> = Vec::new();
+		let mut overflowed = false;
+		let (dimensions, indices) = self.extract_strings(|s| {
+			if let Some(&index) = lookup.get(s) {
+				return index;
 			}
-			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract Tensor<String> from {t}")))
+			let Ok(index) = u32::try_from(dictionary.len()) else {
+				overflowed = true;
+				return u32::MAX;
+			};
+			lookup.insert(s.to_owned(), index);
+			dictionary.push(s.to_owned());
+			index
+		})?;
+		if overflowed {
+			return Err(Error::new("Tensor has too many unique strings to intern"));
 		}
+
+		let indices = ndarray::Array::from_shape_vec(IxDyn(&dimensions.iter().map(|&n| n as usize).collect::<Vec<_>>()), indices)
+			.expect("Shape extracted from tensor didn't match tensor contents");
+		Ok((indices, dictionary))
 	}
 
 	/// Attempt to extract the underlying string data into a "raw" data tuple, consisting of the tensor's dimensions and
@@ -358,7 +1501,80 @@ impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
 	/// # }
 	/// ```
 	pub fn try_extract_raw_string_tensor(&self) -> Result<(Vec<i64>, Vec<String>)> {
-		let dtype = self.dtype();
+		self.extract_strings(str::to_owned)
+	}
+
+	/// Attempt to extract the underlying string data into a "raw" data tuple, consisting of the tensor's dimensions and
+	/// a `Vec` of shared [`Arc<str>`]s.
+	///
+	/// See [`Tensor::try_extract_shared_string_tensor`] for why this may be preferable to
+	/// [`Tensor::try_extract_raw_string_tensor`].
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_string_array(([2], vec!["cat", "dog"].into_boxed_slice()))?;
+	///
+	/// let (shape, labels) = tensor.try_extract_raw_shared_string_tensor()?;
+	/// assert_eq!(shape, [2]);
+	/// assert_eq!(&*labels[0], "cat");
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	pub fn try_extract_raw_shared_string_tensor(&self) -> Result<(Vec<i64>, Vec<Arc<str>>)> {
+		self.extract_strings(|s| Arc::from(s))
+	}
+
+	/// Attempt to extract the underlying string data and parse each element as JSON, e.g. for models that emit a
+	/// structured JSON object per element. Elements are returned in row-major order.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_string_array(([2], vec![r#"{"label":"cat"}"#, "[1, 2]"].into_boxed_slice()))?;
+	///
+	/// let values = tensor.try_extract_json()?;
+	/// assert_eq!(values[0]["label"], "cat");
+	/// assert_eq!(values[1], serde_json::json!([1, 2]));
+	///
+	/// let tensor = Tensor::from_string_array(([2], vec!["{}", "{"].into_boxed_slice()))?;
+	/// assert!(tensor.try_extract_json().is_err());
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a string tensor.
+	/// - Any element is not valid JSON. The error message lists the index of every element that failed to parse, along
+	///   with the position of the error within that element.
+	/// - The tensor's data is not allocated in CPU memory.
+	#[cfg(feature = "serde")]
+	#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+	#[doc(alias = "extract_json")]
+	pub fn try_extract_json(&self) -> Result<Vec<serde_json::Value>> {
+		let (_, parsed) = self.extract_strings(|s| serde_json::from_str::<serde_json::Value>(s))?;
+
+		let mut values = Vec::with_capacity(parsed.len());
+		let mut errors = Vec::new();
+		for (i, value) in parsed.into_iter().enumerate() {
+			match value {
+				Ok(value) => values.push(value),
+				Err(e) => errors.push(format!("element {i}: {e}"))
+			}
+		}
+		if !errors.is_empty() {
+			return Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Failed to parse {} of {} elements as JSON ({})", errors.len(), errors.len() + values.len(), errors.join("; "))
+			));
+		}
+		Ok(values)
+	}
+
+	/// Decodes the string data of this tensor, converting each string into `S` with `convert`.
+	fn extract_strings<S>(&self, mut convert: impl FnMut(&str) -> S) -> Result<(Vec<i64>, Vec<S>)> {
+		let dtype = self.try_dtype()?;
 		match dtype {
 			ValueType::Tensor { ty, dimensions } => {
 				let mem = self.memory_info();
@@ -394,9 +1610,9 @@ impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
 						.windows(2)
 						.map(|w| {
 							let slice = &string_contents[w[0] as _..w[1] as _];
-							String::from_utf8(slice.into())
+							std::str::from_utf8(slice).map(&mut convert)
 						})
-						.collect::<Result<Vec<String>, FromUtf8Error>>()
+						.collect::<Result<Vec<S>, Utf8Error>>()
 						.map_err(Error::wrap)?;
 
 					Ok((dimensions, strings))
@@ -408,8 +1624,46 @@ impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
 		}
 	}
 
+	/// Returns the total size of the tensor's data in bytes.
+	///
+	/// For primitive tensors, this is the number of elements multiplied by the size of each element. For string
+	/// tensors, this is the total length of all strings in bytes, not including any null terminators.
+	///
+	/// Since this only inspects the tensor's metadata, it can be used on tensors which are not CPU-accessible.
+	///
+	/// ```
+	/// # use ort::{Tensor, Value};
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_array(([2usize, 3], vec![0.0_f32; 6].into_boxed_slice()))?;
+	/// assert_eq!(tensor.byte_size()?, 6 * std::mem::size_of::<f32>());
+	///
+	/// let tensor = Tensor::from_string_array(([2], vec!["hello", "world!"].into_boxed_slice()))?;
+	/// assert_eq!(tensor.byte_size()?, 11);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if this is a [`crate::DynValue`], and the value is not actually a tensor.
+	pub fn byte_size(&self) -> Result<usize> {
+		match self.try_dtype()? {
+			ValueType::Tensor { ty, dimensions } => match ty.try_byte_size() {
+				Some(width) => Ok(calculate_tensor_size(&dimensions) * width),
+				None => {
+					let mut total_length: ort_sys::size_t = 0;
+					ortsys![unsafe GetStringTensorDataLength(self.ptr(), &mut total_length)?];
+					Ok(total_length as usize)
+				}
+			},
+			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot get the byte size of {t}; expected a tensor")))
+		}
+	}
+
 	/// Returns the shape of the tensor.
 	///
+	/// Dimensions which are symbolic or otherwise unknown are reported as `-1`, though tensors that hold data (like
+	/// session inputs & outputs) always have a concrete shape.
+	///
 	/// ```
 	/// # use ort::{Allocator, Sequence, Tensor};
 	/// # fn main() -> ort::Result<()> {
@@ -461,6 +1715,8 @@ impl<T: PrimitiveTensorElementType + Debug> Tensor<T> {
 
 	/// Extracts the underlying data into a mutable [`ndarray::ArrayViewMut`].
 	///
+	/// See [`Tensor::try_extract_tensor_mut`] for the caveats of mutating tensors owned by ONNX Runtime.
+	///
 	/// ```
 	/// # use std::sync::Arc;
 	/// # use ort::{Session, Tensor, TensorElementType};
@@ -522,3 +1778,92 @@ impl<T: PrimitiveTensorElementType + Debug> Tensor<T> {
 		self.try_extract_raw_tensor_mut().expect("Failed to extract tensor")
 	}
 }
+
+/// Pairs each label in a string tensor with the corresponding score in an `f32` tensor, as is commonly output by
+/// classifier models.
+///
+/// Both tensors are flattened in row-major order before being paired, so their shapes need not match exactly; only
+/// their total element counts must be equal.
+///
+/// ```
+/// # use ort::{Tensor, zip_string_scores};
+/// # fn main() -> ort::Result<()> {
+/// let labels = Tensor::from_string_array(([3], vec!["cat", "dog", "bird"].into_boxed_slice()))?;
+/// let scores = Tensor::from_array(([1, 3], vec![0.7_f32, 0.2, 0.1].into_boxed_slice()))?;
+///
+/// let pairs = zip_string_scores(&labels, &scores)?;
+/// assert_eq!(pairs, vec![("cat".to_string(), 0.7), ("dog".to_string(), 0.2), ("bird".to_string(), 0.1)]);
+/// # 	Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+/// May return an error if:
+/// - `labels` is not a string tensor, or `scores` is not an `f32` tensor.
+/// - The two tensors do not have the same number of elements.
+/// - Either tensor's data is not allocated in CPU memory.
+pub fn zip_string_scores<L: TensorValueTypeMarker + ?Sized, S: TensorValueTypeMarker + ?Sized>(
+	labels: &Value<L>,
+	scores: &Value<S>
+) -> Result<Vec<(String, f32)>> {
+	let (_, labels) = labels.try_extract_raw_string_tensor()?;
+	let (_, scores) = scores.try_extract_raw_tensor::<f32>()?;
+	if labels.len() != scores.len() {
+		return Err(Error::new_with_code(
+			ErrorCode::InvalidArgument,
+			format!("Cannot pair {} labels with {} scores; element counts must match", labels.len(), scores.len())
+		));
+	}
+
+	Ok(labels.into_iter().zip(scores.iter().copied()).collect())
+}
+
+/// Splits a rank-2 `[N, C]` numeric tensor (e.g. a classifier's logits or a batch of embeddings) into one `Vec<f32>`
+/// per row, pairing each row with the corresponding label in a string tensor of `N` elements. If `labels` is `None`,
+/// each row is labeled with its index instead.
+///
+/// Elements of non-`f32` tensors are converted to `f32`, rounding if necessary.
+///
+/// ```
+/// # use ort::{DynValue, Tensor, zip_string_rows};
+/// # fn main() -> ort::Result<()> {
+/// let labels = Tensor::from_string_array(([2], vec!["first", "second"].into_boxed_slice()))?;
+/// let logits = Tensor::from_array(([2, 3], vec![0.5_f32, 1.5, -1.0, 2.0, 0.0, 1.0].into_boxed_slice()))?;
+///
+/// let rows = zip_string_rows(Some(&labels), &logits)?;
+/// assert_eq!(rows, vec![("first".to_string(), vec![0.5, 1.5, -1.0]), ("second".to_string(), vec![2.0, 0.0, 1.0])]);
+///
+/// let rows = zip_string_rows(None::<&DynValue>, &logits)?;
+/// assert_eq!(rows[1].0, "1");
+/// # 	Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+/// May return an error if:
+/// - `features` is not a real numeric tensor of rank 2.
+/// - `labels` is not a string tensor, or does not have one element per row of `features`.
+/// - Either tensor's data is not allocated in CPU memory.
+pub fn zip_string_rows<L: TensorValueTypeMarker + ?Sized, F: TensorValueTypeMarker + ?Sized>(
+	labels: Option<&Value<L>>,
+	features: &Value<F>
+) -> Result<Vec<(String, Vec<f32>)>> {
+	let (shape, data) = features.to_f64_vec()?;
+	let &[rows, cols] = shape.as_slice() else {
+		return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Expected a feature tensor of rank 2, got a tensor of shape {shape:?}")));
+	};
+	let (rows, cols) = (rows as usize, cols as usize);
+	let labels = match labels {
+		Some(labels) => labels.try_extract_raw_string_tensor()?.1,
+		None => (0..rows).map(|i| i.to_string()).collect()
+	};
+	if labels.len() != rows {
+		return Err(Error::new_with_code(
+			ErrorCode::InvalidArgument,
+			format!("Cannot pair {} labels with {rows} rows; element counts must match", labels.len())
+		));
+	}
+
+	let mut data = data.into_iter().map(|x| x as f32);
+	Ok(labels.into_iter().map(|label| (label, data.by_ref().take(cols).collect())).collect())
+}
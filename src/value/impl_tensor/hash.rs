@@ -0,0 +1,60 @@
+use xxhash_rust::xxh3::Xxh3;
+
+use super::{TensorValueTypeMarker, dynamic::TensorData};
+use crate::{error::Result, value::Value};
+
+impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
+	/// Computes a hash of this tensor's element type, shape, and contents, e.g. to use as the key of a cache of
+	/// inference results.
+	///
+	/// The hash is computed with [XXH3](https://xxhash.com/) and is stable: unlike [`std::hash::Hash`], it does not
+	/// depend on the process, the platform, or the version of Rust, so it may be persisted. (The raw bytes of
+	/// primitive elements are hashed in memory order, which is always little-endian since ONNX Runtime only supports
+	/// little-endian platforms.)
+	///
+	/// Since elements are hashed by their bit patterns, floating point tensors which compare equal may not hash
+	/// equally: `-0.0` and `+0.0` hash differently, and NaNs hash according to their payload.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let a = Tensor::from_array(([2usize, 2], vec![1.0_f32, 2.0, 3.0, 4.0]))?;
+	/// let b = Tensor::from_array(([2usize, 2], vec![1.0_f32, 2.0, 3.0, 4.0]))?;
+	/// assert_eq!(a.content_hash()?, b.content_hash()?);
+	///
+	/// // the shape & element type are part of the hash
+	/// let c = Tensor::from_array(([4usize], vec![1.0_f32, 2.0, 3.0, 4.0]))?;
+	/// assert_ne!(a.content_hash()?, c.content_hash()?);
+	/// let d = Tensor::from_array(([2usize, 2], vec![1_i32, 2, 3, 4]))?;
+	/// assert_ne!(a.content_hash()?, d.content_hash()?);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor's element type is not supported by this build.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn content_hash(&self) -> Result<u64> {
+		let data = self.try_extract_dynamic()?;
+
+		let mut hasher = Xxh3::new();
+		hasher.update(&(ort_sys::ONNXTensorElementDataType::from(data.element_type()) as u32).to_le_bytes());
+		hasher.update(&(data.shape().len() as u64).to_le_bytes());
+		for dim in data.shape() {
+			hasher.update(&dim.to_le_bytes());
+		}
+		match &data {
+			TensorData::PrimitiveView { data, .. } => hasher.update(data),
+			TensorData::Strings { data, .. } => {
+				for s in data {
+					// length-prefix each string so that e.g. ["ab", "c"] and ["a", "bc"] hash differently
+					hasher.update(&(s.len() as u64).to_le_bytes());
+					hasher.update(s.as_bytes());
+				}
+			}
+		}
+		Ok(hasher.digest())
+	}
+}
@@ -1,5 +1,17 @@
+#[cfg(feature = "candle")]
+mod candle;
+mod convert;
 mod create;
+mod diff;
+#[cfg(feature = "dlpack")]
+pub mod dlpack;
+mod dynamic;
 mod extract;
+mod hash;
+#[cfg(feature = "polars")]
+mod polars;
+mod serialize;
+mod visit;
 
 use std::{
 	fmt::Debug,
@@ -9,8 +21,21 @@ use std::{
 	sync::Arc
 };
 
-use super::{DowncastableTarget, DynValue, Value, ValueRef, ValueRefMut, ValueType, ValueTypeMarker};
-use crate::{error::Result, memory::MemoryInfo, ortsys, tensor::IntoTensorElementType};
+pub use self::{
+	convert::{ExtractInto, FromValue, ToValue},
+	create::Rounding,
+	diff::DiffSummary,
+	dynamic::TensorData,
+	extract::{zip_string_rows, zip_string_scores},
+	visit::TensorVisitor
+};
+use super::{DowncastableTarget, DynValue, Value, ValueRef, ValueRefMut, ValueType, ValueTypeMarker, extract_data_type_from_tensor_info};
+use crate::{
+	error::{Error, ErrorCode, Result, status_to_result},
+	memory::MemoryInfo,
+	ortsys,
+	tensor::{IntoTensorElementType, TensorElementType}
+};
 
 pub trait TensorValueTypeMarker: ValueTypeMarker {
 	crate::private_trait!();
@@ -56,6 +81,22 @@ pub type TensorRef<'v, T> = ValueRef<'v, TensorValueType<T>>;
 /// A mutable reference to a strongly-typed tensor [`Value`].
 pub type TensorRefMut<'v, T> = ValueRefMut<'v, TensorValueType<T>>;
 
+/// A C-compatible description of a tensor's data, element type, and shape, as returned by [`Tensor::descriptor`].
+///
+/// This is intended to be passed across an FFI boundary as a single, stable struct.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct TensorDescriptor {
+	/// A pointer to the tensor's data, which may not be CPU-accessible; see [`Tensor::data_ptr`].
+	pub data: *mut ort_sys::c_void,
+	/// The tensor's element type, as an ONNX `TensorProto.DataType` value (e.g. `1` for `float`, `7` for `int64`).
+	pub dtype: i32,
+	/// The number of dimensions of the tensor.
+	pub rank: usize,
+	/// A pointer to `rank` dimensions.
+	pub dims: *const i64
+}
+
 impl DowncastableTarget for DynTensorValueType {
 	fn can_downcast(dtype: &ValueType) -> bool {
 		matches!(dtype, ValueType::Tensor { .. })
@@ -116,6 +157,55 @@ impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
 		Ok(buffer_ptr)
 	}
 
+	/// Describes this tensor's data pointer, element type, and shape in a single C-compatible [`TensorDescriptor`].
+	///
+	/// The shape is cached by the value the first time this is called, so the descriptor's `dims` pointer (and `data`
+	/// pointer) remain valid for as long as this value is alive. A [`ValueRef`] obtained via e.g. [`Tensor::view`]
+	/// shares its cache with the value it was created from, so descriptors obtained from the reference remain valid for
+	/// as long as that value is alive.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_array(([2usize, 3], vec![0_i64; 6]))?;
+	/// let descriptor = tensor.descriptor()?;
+	/// assert_eq!(descriptor.dtype, 7);
+	/// assert_eq!(descriptor.rank, 2);
+	/// assert_eq!(unsafe { std::slice::from_raw_parts(descriptor.dims, descriptor.rank) }, &[2, 3]);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is a string tensor, whose data cannot be described by a single pointer.
+	pub fn descriptor(&self) -> Result<TensorDescriptor> {
+		let mut info_ptr: *mut ort_sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
+		ortsys![unsafe GetTensorTypeAndShape(self.ptr(), &mut info_ptr)?; nonNull(info_ptr)];
+		let mut ty = ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
+		let status = ortsys![unsafe GetTensorElementType(info_ptr, &mut ty)];
+		ortsys![unsafe ReleaseTensorTypeAndShapeInfo(info_ptr)];
+		status_to_result(status)?;
+		if ty == ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_STRING {
+			return Err(Error::new_with_code(ErrorCode::InvalidArgument, "Cannot describe the data of a string tensor"));
+		}
+
+		let dims = match self.inner.dims().get() {
+			Some(dims) => dims,
+			None => {
+				let shape = self.shape()?;
+				self.inner.dims().get_or_init(|| shape.into_boxed_slice())
+			}
+		};
+		Ok(TensorDescriptor {
+			data: self.data_ptr()?.cast_mut(),
+			dtype: ty as i32,
+			rank: dims.len(),
+			dims: dims.as_ptr()
+		})
+	}
+
 	/// Returns information about the device this tensor is allocated on.
 	///
 	/// ```
@@ -143,6 +233,178 @@ impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
 		ortsys![unsafe GetTensorMemoryInfo(self.ptr(), &mut memory_info_ptr)];
 		MemoryInfo::from_raw(unsafe { NonNull::new_unchecked(memory_info_ptr.cast_mut()) }, false)
 	}
+
+	/// Returns the element type and shape of this tensor, fetched with a single query to ONNX Runtime.
+	///
+	/// This is cheaper than calling both [`Value::dtype`] and [`Tensor::shape`] when multiple properties of the tensor
+	/// are needed.
+	///
+	/// ```
+	/// # use ort::{Allocator, Tensor, TensorElementType};
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::<f32>::new(&Allocator::default(), [1, 3, 224, 224])?;
+	///
+	/// let info = tensor.type_info()?;
+	/// assert_eq!(info.element_type(), TensorElementType::Float32);
+	/// assert_eq!(info.shape(), &[1, 3, 224, 224]);
+	/// assert_eq!(info.rank(), 4);
+	/// assert_eq!(info.element_count(), 3 * 224 * 224);
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if this is a [`crate::DynValue`], and the value is not actually a tensor.
+	pub fn type_info(&self) -> Result<TensorTypeInfo> {
+		let mut info_ptr: *mut ort_sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
+		ortsys![unsafe GetTensorTypeAndShape(self.ptr(), &mut info_ptr)?; nonNull(info_ptr)];
+		let dtype = unsafe { extract_data_type_from_tensor_info(info_ptr) };
+		ortsys![unsafe ReleaseTensorTypeAndShapeInfo(info_ptr)];
+		match dtype? {
+			ValueType::Tensor { ty, dimensions } => Ok(TensorTypeInfo { element_type: ty, shape: dimensions }),
+			_ => unreachable!()
+		}
+	}
+
+	/// Returns the element type of this tensor, without querying its shape.
+	///
+	/// Unlike [`Value::dtype`], this does not panic if the value is not a tensor, or if its element type is not
+	/// supported by this build.
+	///
+	/// ```
+	/// # use ort::{Allocator, DynValue, Tensor, TensorElementType};
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor: DynValue = Tensor::<i64>::new(&Allocator::default(), [2, 2])?.into_dyn();
+	/// assert_eq!(tensor.element_type()?, TensorElementType::Int64);
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor's element type is not supported by this build.
+	pub fn element_type(&self) -> Result<TensorElementType> {
+		let mut info_ptr: *mut ort_sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
+		ortsys![unsafe GetTensorTypeAndShape(self.ptr(), &mut info_ptr)?; nonNull(info_ptr)];
+		let mut ty = ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
+		let status = ortsys![unsafe GetTensorElementType(info_ptr, &mut ty)];
+		ortsys![unsafe ReleaseTensorTypeAndShapeInfo(info_ptr)];
+		status_to_result(status)?;
+		TensorElementType::try_from(ty)
+	}
+
+	/// Returns the strides of this tensor, in elements, for each dimension.
+	///
+	/// ONNX Runtime tensors are always contiguous & row-major, so the stride of a dimension is the product of all
+	/// dimensions after it. Multiply by the element type's size to get byte strides, e.g. when handing the tensor's
+	/// buffer to an array protocol like DLPack which requires explicit strides.
+	///
+	/// The strides can also be used to index into the tensor's flat data buffer, as the offset of an element is the
+	/// dot product of its index and the strides:
+	/// ```
+	/// # use ort::{Allocator, Tensor};
+	/// # fn main() -> ort::Result<()> {
+	/// let mut tensor = Tensor::<f32>::new(&Allocator::default(), [1, 3, 224, 224])?;
+	/// let strides = tensor.strides()?;
+	/// assert_eq!(strides, vec![3 * 224 * 224, 224 * 224, 224, 1]);
+	///
+	/// let offset: usize = [0, 2, 42, 7].iter().zip(&strides).map(|(i, s)| i * s).sum();
+	/// tensor.extract_raw_tensor_mut().1[offset] = 1.0;
+	/// assert_eq!(tensor[[0, 2, 42, 7]], 1.0);
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if this is a [`crate::DynValue`], and the value is not actually a tensor.
+	#[doc(alias = "axis_strides")]
+	pub fn strides(&self) -> Result<Vec<usize>> {
+		let dimensions = match self.try_dtype()? {
+			ValueType::Tensor { dimensions, .. } => dimensions,
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot get the strides of {t}; expected a tensor")))
+		};
+		let mut strides = vec![1; dimensions.len()];
+		for i in (1..dimensions.len()).rev() {
+			strides[i - 1] = strides[i] * dimensions[i].max(0) as usize;
+		}
+		Ok(strides)
+	}
+
+	/// Checks that this tensor has the given element type and shape, returning a descriptive error if it does not.
+	///
+	/// A dimension of `-1` in `expected_shape` matches any size, so the `dimensions` of a session input's
+	/// [`ValueType`] can be passed directly to validate a value before running the session.
+	///
+	/// ```
+	/// # use ort::{Tensor, TensorElementType};
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::<f32>::from_array(([1usize, 3, 224, 224], vec![0.0; 3 * 224 * 224].into_boxed_slice()))?;
+	/// assert!(tensor.validate_against(TensorElementType::Float32, &[-1, 3, 224, 224]).is_ok());
+	/// assert!(tensor.validate_against(TensorElementType::Float32, &[-1, 3, 256, 256]).is_err());
+	/// assert!(tensor.validate_against(TensorElementType::Int64, &[-1, 3, 224, 224]).is_err());
+	/// # Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// Returns an error with code [`ErrorCode::InvalidArgument`] if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor's element type is not `expected_type`.
+	/// - The tensor's rank differs from the length of `expected_shape`, or any non-dynamic dimension differs.
+	pub fn validate_against(&self, expected_type: TensorElementType, expected_shape: &[i64]) -> Result<()> {
+		let (ty, dimensions) = match self.try_dtype()? {
+			ValueType::Tensor { ty, dimensions } => (ty, dimensions),
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Expected Tensor<{expected_type}>, got {t}")))
+		};
+		if ty != expected_type {
+			return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Expected Tensor<{expected_type}>, got Tensor<{ty}>")));
+		}
+		if dimensions.len() != expected_shape.len() {
+			return Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Expected a tensor of rank {} (shape {expected_shape:?}), got rank {} (shape {dimensions:?})", expected_shape.len(), dimensions.len())
+			));
+		}
+		for (i, (&actual, &expected)) in dimensions.iter().zip(expected_shape).enumerate() {
+			if expected != -1 && actual != expected {
+				return Err(Error::new_with_code(
+					ErrorCode::InvalidArgument,
+					format!("Expected dimension {i} to be {expected}, got {actual} (expected shape {expected_shape:?}, got {dimensions:?})")
+				));
+			}
+		}
+		Ok(())
+	}
+}
+
+/// The element type and shape of a tensor, as returned by [`Tensor::type_info`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TensorTypeInfo {
+	element_type: TensorElementType,
+	shape: Vec<i64>
+}
+
+impl TensorTypeInfo {
+	/// Returns the type of the tensor's elements.
+	pub fn element_type(&self) -> TensorElementType {
+		self.element_type
+	}
+
+	/// Returns the dimensions of the tensor.
+	pub fn shape(&self) -> &[i64] {
+		&self.shape
+	}
+
+	/// Returns the number of dimensions of the tensor.
+	pub fn rank(&self) -> usize {
+		self.shape.len()
+	}
+
+	/// Returns the total number of elements in the tensor.
+	pub fn element_count(&self) -> usize {
+		calculate_tensor_size(&self.shape)
+	}
 }
 
 impl<T: IntoTensorElementType + Debug> Tensor<T> {
@@ -274,7 +536,7 @@ mod tests {
 
 	use ndarray::{ArcArray1, Array1, CowArray};
 
-	use crate::{Allocator, Tensor, TensorElementType, ValueType};
+	use crate::{Allocator, DynTensor, Tensor, TensorElementType, ValueType};
 
 	#[test]
 	#[cfg(feature = "ndarray")]
@@ -344,6 +606,20 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_string_tensor_interned() -> crate::Result<()> {
+		let v = ndarray::arr2(&[["b", "a", "b"], ["", "a", "b"]]);
+
+		let value = Tensor::from_string_array(v.view())?;
+		let (indices, dictionary) = value.try_extract_interned_string_tensor()?;
+		assert_eq!(dictionary, ["b", "a", ""]);
+		assert_eq!(indices, ndarray::arr2(&[[0, 1, 0], [2, 1, 0]]).into_dyn());
+		assert_eq!(indices.mapv(|i| dictionary[i as usize].as_str()), v.into_dyn());
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_string_tensor_raw() -> crate::Result<()> {
 		let v = vec!["hello world".to_string(), "こんにちは世界".to_string()];
@@ -356,6 +632,25 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_string_tensor_string_types() -> crate::Result<()> {
+		use std::borrow::Cow;
+
+		let cow: Vec<Cow<'static, str>> = vec![Cow::Borrowed("borrowed"), Cow::Owned("owned".to_string())];
+		let value = Tensor::from_string_array(([2usize], cow))?;
+		assert_eq!(value.try_extract_raw_string_tensor()?.1, ["borrowed", "owned"]);
+
+		let boxed: Vec<Box<str>> = vec!["boxed".into()];
+		let value = Tensor::from_string_array(([1usize], boxed))?;
+		assert_eq!(value.try_extract_raw_string_tensor()?.1, ["boxed"]);
+
+		let shared: Vec<Arc<str>> = vec!["shared".into()];
+		let value = Tensor::from_string_array(([1usize], shared))?;
+		assert_eq!(value.try_extract_raw_string_tensor()?.1, ["shared"]);
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_tensor_raw_inputs() -> crate::Result<()> {
 		let v: Vec<f32> = vec![1., 2., 3., 4., 5.];
@@ -374,6 +669,508 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_tensor_from_vec_zero_copy() -> crate::Result<()> {
+		let v: Vec<i64> = (0..64).collect();
+		let v_ptr = v.as_ptr();
+
+		let value = Tensor::from_array(([8usize, 8], v))?;
+		assert_eq!(value.data_ptr()?.cast::<i64>(), v_ptr);
+		assert_eq!(value.extract_raw_tensor().1[63], 63);
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_broadcast_to() -> crate::Result<()> {
+		// size-1 dimensions are stretched, and missing leading dimensions are added
+		let column = ndarray::arr2(&[[1_i32], [2]]);
+		let tensor = Tensor::broadcast_to(column.view(), &[2, 2, 3])?;
+		let (shape, data) = tensor.extract_raw_tensor();
+		assert_eq!(shape, [2, 2, 3]);
+		assert_eq!(data, &[1, 1, 1, 2, 2, 2, 1, 1, 1, 2, 2, 2]);
+
+		let scalar = ndarray::arr0(7_i32);
+		assert_eq!(Tensor::broadcast_to(scalar.view(), &[3])?.extract_raw_tensor().1, &[7, 7, 7]);
+
+		// a size-1 dimension may also be broadcast to 0
+		assert_eq!(Tensor::broadcast_to(column.view(), &[2, 0])?.extract_raw_tensor().0, [2, 0]);
+
+		assert!(Tensor::broadcast_to(column.view(), &[3, 3]).is_err());
+		assert!(Tensor::broadcast_to(column.view(), &[1]).is_err());
+		assert!(Tensor::broadcast_to(column.view(), &[2, -1]).is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_try_extract_as() -> crate::Result<()> {
+		let value = Tensor::from_array(([4usize], vec![0_i64, -1, i32::MAX as i64, i32::MAX as i64 + 1]))?;
+		assert!(value.try_extract_as::<i32>().is_err());
+		assert_eq!(value.try_extract_as::<f64>()?, Array1::from_vec(vec![0.0, -1.0, i32::MAX as f64, i32::MAX as f64 + 1.0]).into_dyn());
+
+		let value = Tensor::from_array(([3usize], vec![1.0_f32, -2.0, 3.0]))?;
+		assert_eq!(value.try_extract_as::<i8>()?, Array1::from_vec(vec![1, -2, 3]).into_dyn());
+		assert!(value.try_extract_as::<u8>().is_err());
+
+		let value = Tensor::from_array(([1usize], vec![0.5_f64]))?;
+		assert!(value.try_extract_as::<i64>().is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_try_extract_reshaped() -> crate::Result<()> {
+		let value = Tensor::from_array(([2usize, 6], (0..12).collect::<Vec<i32>>()))?;
+		assert_eq!(value.try_extract_reshaped::<i32>(&[3, -1, 2])?.shape(), &[3, 2, 2]);
+		assert_eq!(value.try_extract_reshaped::<i32>(&[12])?, Array1::from_vec((0..12).collect()).into_dyn());
+
+		assert!(value.try_extract_reshaped::<i32>(&[-1, -1]).is_err());
+		assert!(value.try_extract_reshaped::<i32>(&[-1, 5]).is_err());
+		assert!(value.try_extract_reshaped::<i32>(&[-1, 0]).is_err());
+		assert!(value.try_extract_reshaped::<i32>(&[3, -2]).is_err());
+		assert!(value.try_extract_reshaped::<i32>(&[3, 3]).is_err());
+
+		let err = value
+			.try_extract_reshaped::<i32>(&[i64::MAX, i64::MAX, -1])
+			.expect_err("the product of the dimensions overflows a usize");
+		assert_eq!(err.code(), crate::ErrorCode::InvalidArgument);
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_try_extract_fortran() -> crate::Result<()> {
+		let value = Tensor::from_array(([2usize, 3, 2], (0..12).collect::<Vec<i32>>()))?;
+		let fortran = value.try_extract_fortran::<i32>()?;
+		assert_eq!(fortran, value.try_extract_tensor::<i32>()?);
+		assert!(fortran.t().is_standard_layout());
+		assert_eq!(fortran.as_slice_memory_order(), Some(&[0, 6, 2, 8, 4, 10, 1, 7, 3, 9, 5, 11][..]));
+
+		assert!(value.try_extract_fortran::<i64>().is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_predict_classes() -> crate::Result<()> {
+		let value = Tensor::from_array(([2usize, 2, 3], vec![0.0_f64, 1.0, 1.0, f64::NAN, -1.0, -2.0, 5.0, 5.0, 4.0, 0.0, 0.0, 0.5]))?;
+		assert_eq!(value.predict_classes()?, vec![1, 1, 0, 2]);
+
+		assert!(Tensor::from_array(([2usize], vec![f32::NAN, f32::NAN]))?.predict_classes().is_err());
+		assert!(Tensor::from_array(ndarray::Array2::<f32>::zeros((2, 0)))?.predict_classes().is_err());
+		assert!(Tensor::from_array(((), vec![1.0_f32]))?.predict_classes().is_err());
+		assert!(Tensor::from_array(([2usize], vec![1_i32, 2]))?.predict_classes().is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "complex")]
+	fn test_complex_tensor() -> crate::Result<()> {
+		use num_complex::Complex;
+
+		let data = vec![Complex::new(1.0_f32, -1.0), Complex::new(0.0, 2.5)];
+		let value = Tensor::from_array(([2usize], data.clone()))?;
+		assert_eq!(value.dtype().tensor_type(), Some(TensorElementType::Complex64));
+		assert_eq!(value.try_extract_tensor::<Complex<f32>>()?, Array1::from_vec(data).into_dyn());
+		assert!(value.try_extract_tensor::<Complex<f64>>().is_err());
+		assert!(value.min_max().is_err());
+
+		let value = Tensor::from_array(([1usize], vec![Complex::new(0.0_f64, 0.0)]))?;
+		assert_eq!(value.dtype().tensor_type(), Some(TensorElementType::Complex128));
+		assert!(value.is_all_zero()?);
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "complex")]
+	fn test_complex_lossless_conversion() {
+		assert!(TensorElementType::Complex64.can_losslessly_convert_to(TensorElementType::Complex128));
+		assert!(!TensorElementType::Complex128.can_losslessly_convert_to(TensorElementType::Complex64));
+		// complex -> real
+		assert!(!TensorElementType::Complex64.can_losslessly_convert_to(TensorElementType::Float32));
+		assert!(!TensorElementType::Complex64.can_losslessly_convert_to(TensorElementType::Float64));
+		assert!(!TensorElementType::Complex128.can_losslessly_convert_to(TensorElementType::Bool));
+		// real -> complex, if the real part fits
+		assert!(TensorElementType::Bool.can_losslessly_convert_to(TensorElementType::Complex64));
+		assert!(TensorElementType::Float32.can_losslessly_convert_to(TensorElementType::Complex64));
+		assert!(TensorElementType::Float32.can_losslessly_convert_to(TensorElementType::Complex128));
+		assert!(TensorElementType::Int32.can_losslessly_convert_to(TensorElementType::Complex128));
+		assert!(!TensorElementType::Float64.can_losslessly_convert_to(TensorElementType::Complex64));
+		assert!(!TensorElementType::Int32.can_losslessly_convert_to(TensorElementType::Complex64));
+		assert!(!TensorElementType::String.can_losslessly_convert_to(TensorElementType::Complex64));
+	}
+
+	#[test]
+	#[cfg(all(feature = "fp8", feature = "ndarray"))]
+	fn test_fp8_tensor() -> crate::Result<()> {
+		use float8::{F8E4M3, F8E5M2};
+
+		let data = vec![F8E4M3::from_f32(1.0), F8E4M3::from_f32(-0.5), F8E4M3::from_f32(448.0)];
+		let value = Tensor::from_array(([3usize], data.clone()))?;
+		assert_eq!(value.dtype().tensor_type(), Some(TensorElementType::Float8E4M3FN));
+		assert_eq!(value.extract_raw_tensor().1, &data);
+		assert_eq!(value.try_extract_as::<f32>()?, Array1::from_vec(vec![1.0, -0.5, 448.0]).into_dyn());
+		assert_eq!(value.min_max()?, (-0.5, 448.0));
+
+		let value = Tensor::from_array(([2usize], vec![F8E5M2::from_f32(2.0), F8E5M2::from_f32(f32::INFINITY)]))?;
+		assert_eq!(value.dtype().tensor_type(), Some(TensorElementType::Float8E5M2));
+		assert_eq!(value.count_non_finite()?, 1);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_try_extract_into_uninit() -> crate::Result<()> {
+		let value = Tensor::from_array(([2usize, 2], vec![1_i64, 2, 3, 4]))?;
+
+		let mut buffer = vec![std::mem::MaybeUninit::<i64>::uninit(); 5];
+		assert_eq!(value.try_extract_into_uninit(&mut buffer)?, &[1, 2, 3, 4]);
+
+		let mut buffer = [std::mem::MaybeUninit::<i64>::uninit(); 3];
+		assert!(value.try_extract_into_uninit(&mut buffer).is_err());
+		let mut buffer = [std::mem::MaybeUninit::<i32>::uninit(); 4];
+		assert!(value.try_extract_into_uninit(&mut buffer).is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_mode() -> crate::Result<()> {
+		// ties go to the smallest value
+		assert_eq!(Tensor::from_array(([6usize], vec![2_i32, -1, 2, 5, -1, 0]))?.mode()?, (-1, 2));
+		assert_eq!(Tensor::from_array(([3usize], vec![7_u8, 7, 7]))?.mode()?, (7, 3));
+
+		assert!(Tensor::from_array(([2usize], vec![u64::MAX, u64::MAX]))?.mode().is_err());
+		assert!(Tensor::from_array(Array1::<i64>::zeros(0))?.mode().is_err());
+		assert!(Tensor::from_array(([1usize], vec![1.0_f32]))?.mode().is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_quantile() -> crate::Result<()> {
+		let value = Tensor::from_array(([6usize], vec![6.0_f32, f32::NAN, 2.0, 8.0, 4.0, 10.0]))?;
+		assert_eq!(value.quantile(0.0)?, 2.0);
+		assert_eq!(value.quantile(0.5)?, 6.0);
+		assert_eq!(value.quantile(0.3)?, 4.4);
+		assert_eq!(value.quantile(1.0)?, 10.0);
+		assert!(value.quantile(1.5).is_err());
+
+		let value = Tensor::from_array(([1usize], vec![7_u8]))?;
+		assert_eq!(value.quantile(0.9)?, 7.0);
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_l2_normalize() -> crate::Result<()> {
+		let value = Tensor::from_array(([2usize, 3], vec![3_i64, 0, 0, 4, 0, 0]))?;
+		assert_eq!(value.l2_normalize(0)?, ndarray::arr2(&[[0.6, 0.0, 0.0], [0.8, 0.0, 0.0]]).into_dyn());
+		assert_eq!(value.l2_normalize(1)?, ndarray::arr2(&[[1.0, 0.0, 0.0], [1.0, 0.0, 0.0]]).into_dyn());
+		assert!(value.l2_normalize(2).is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_to_value() -> crate::Result<()> {
+		use crate::ToValue;
+
+		let value = ([2usize, 2], vec![1_i32, 2, 3, 4]).to_value()?;
+		assert_eq!(value.try_extract_raw_tensor::<i32>()?, (vec![2, 2], &[1, 2, 3, 4][..]));
+
+		let value = 7_u8.to_value()?;
+		assert_eq!(value.try_extract_scalar::<u8>()?, 7);
+
+		let value = ([2usize], vec!["a".to_owned(), "b".to_owned()]).to_value()?;
+		assert_eq!(value.try_extract_raw_string_tensor()?.1, ["a", "b"]);
+
+		#[cfg(feature = "ndarray")]
+		{
+			let array = ndarray::arr2(&[[1.0_f32, 2.0], [3.0, 4.0]]);
+			let value = array.t().to_value()?;
+			assert_eq!(value.try_extract_tensor::<f32>()?, array.t().into_dyn());
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_from_value() -> crate::Result<()> {
+		use crate::{FromValue, TensorData};
+
+		let value = Tensor::from_array(((), vec![2.5_f64]))?;
+		assert_eq!(f64::from_value(&value)?, 2.5);
+
+		let value = Tensor::from_string_array(([2usize], vec!["a", "b"].into_boxed_slice()))?;
+		assert_eq!(Vec::<String>::from_value(&value)?, ["a", "b"]);
+		assert!(matches!(TensorData::from_value(&value)?, TensorData::Strings { .. }));
+
+		#[cfg(feature = "ndarray")]
+		{
+			let value = Tensor::from_array(([2usize, 3], vec![1_i64, 2, 3, 4, 5, 6]))?;
+			let view = ndarray::ArrayView2::<i64>::from_value(&value)?;
+			assert_eq!(view, ndarray::arr2(&[[1, 2, 3], [4, 5, 6]]));
+			assert!(ndarray::Array3::<i64>::from_value(&value).is_err());
+			assert!(ndarray::Array2::<f32>::from_value(&value).is_err());
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_from_array_as() -> crate::Result<()> {
+		let array = ndarray::arr1(&[0_u64, 255, u64::MAX]);
+		assert_eq!(Tensor::<f64>::from_array_as(&array)?.extract_raw_tensor().1, &[0.0, 255.0, u64::MAX as f64]);
+		let err = Tensor::<u8>::from_array_as(&array).expect_err("u64::MAX does not fit in a u8");
+		assert_eq!(err.code(), crate::ErrorCode::InvalidArgument);
+		assert!(err.message().contains("Element 2"));
+
+		let array = ndarray::arr2(&[[-1_i32, 2], [3, 4]]);
+		let tensor = Tensor::<i8>::from_array_as(&array.t())?;
+		assert_eq!(tensor.extract_raw_tensor(), (vec![2, 2], &[-1_i8, 3, 2, 4][..]));
+		assert!(Tensor::<u16>::from_array_as(&array).is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_from_bool_bytes() -> crate::Result<()> {
+		let tensor = Tensor::from_bool_bytes([3], &[1, 0, 1])?;
+		assert_eq!(tensor.extract_raw_tensor(), (vec![3], &[true, false, true][..]));
+
+		let err = Tensor::from_bool_bytes([3], &[1, 0, 2]).expect_err("2 is not a valid boolean byte");
+		assert_eq!(err.code(), crate::ErrorCode::InvalidArgument);
+		assert!(err.message().contains("index 2"));
+
+		assert!(Tensor::from_bool_bytes([2], &[1, 0, 1]).is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn test_content_hash() -> crate::Result<()> {
+		let a = Tensor::from_array(([2usize], vec![0.0_f32, 1.0]))?;
+		assert_eq!(a.content_hash()?, Tensor::from_array(([2usize], vec![0.0_f32, 1.0]))?.content_hash()?);
+		assert_ne!(a.content_hash()?, Tensor::from_array(([2usize], vec![-0.0_f32, 1.0]))?.content_hash()?);
+		assert_ne!(a.content_hash()?, Tensor::from_array(([1usize, 2], vec![0.0_f32, 1.0]))?.content_hash()?);
+
+		let ab_c = Tensor::from_string_array(([2usize], vec!["ab", "c"].into_boxed_slice()))?;
+		let a_bc = Tensor::from_string_array(([2usize], vec!["a", "bc"].into_boxed_slice()))?;
+		assert_ne!(ab_c.content_hash()?, a_bc.content_hash()?);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_extend_into() -> crate::Result<()> {
+		use std::collections::VecDeque;
+
+		use super::ExtractInto;
+
+		let tensor = Tensor::from_array(([3usize], vec![1_i32, 2, 3]))?;
+		let mut buffer = vec![0_i32];
+		tensor.extend_into(&mut buffer)?;
+		tensor.extend_into(&mut buffer)?;
+		assert_eq!(buffer, [0, 1, 2, 3, 1, 2, 3]);
+
+		let mut ring: VecDeque<i32> = VecDeque::new();
+		ring.extend_from_tensor(&tensor)?;
+		assert_eq!(ring, [1, 2, 3]);
+
+		let mut wrong_type: Vec<f32> = vec![1.0];
+		assert!(tensor.extend_into(&mut wrong_type).is_err());
+		assert_eq!(wrong_type, [1.0]);
+
+		let strings = Tensor::from_string_array(([2usize], vec!["a", "b"].into_boxed_slice()))?;
+		let mut labels = vec!["z".to_string()];
+		strings.extend_into(&mut labels)?;
+		assert_eq!(labels, ["z", "a", "b"]);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_element_type() -> crate::Result<()> {
+		let tensor = Tensor::<u16>::new(&Allocator::default(), [3, 1])?;
+		assert_eq!(tensor.element_type()?, TensorElementType::Uint16);
+		assert_eq!(tensor.shape()?, [3, 1]);
+
+		let tensor = Tensor::from_string_array(([1usize], vec!["a"].into_boxed_slice()))?.into_dyn();
+		assert_eq!(tensor.element_type()?, TensorElementType::String);
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_from_slice() -> crate::Result<()> {
+		let data = vec![1_i64, 2, 3, 4];
+		let tensor = Tensor::from_slice([2_i64, 2], &data)?;
+		assert_eq!(tensor.data_ptr()?.cast::<i64>(), data.as_ptr());
+		assert_eq!(tensor.extract_raw_tensor(), (vec![2, 2], &data[..]));
+		// the view must not be able to escape the lifetime of the slice
+		assert!(tensor.try_upgrade().is_err());
+
+		let err = Tensor::from_slice([3_i64], &data).expect_err("the shape does not match the length of the slice");
+		assert_eq!(err.code(), crate::ErrorCode::InvalidArgument);
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "dlpack")]
+	fn test_to_dlpack_borrowed() -> crate::Result<()> {
+		let data = vec![1.0_f32, 2.0, 3.0];
+		let view = Tensor::from_slice([3_i64], &data)?;
+		let err = view.to_dlpack().expect_err("the export could outlive the borrowed slice");
+		assert_eq!(err.code(), crate::ErrorCode::InvalidArgument);
+
+		let tensor = Tensor::from_array(([3usize], data.clone()))?;
+		let managed = tensor.to_dlpack()?;
+		unsafe { ((*managed).deleter.expect("exported tensors always have a deleter"))(managed) };
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_try_extract_json() -> crate::Result<()> {
+		let tensor = Tensor::from_string_array(([2usize, 2], vec!["null", r#"{"a": [1, 2.5]}"#, "\"text\"", "true"].into_boxed_slice()))?;
+		let values = tensor.try_extract_json()?;
+		assert_eq!(values, [serde_json::Value::Null, serde_json::json!({ "a": [1, 2.5] }), serde_json::json!("text"), serde_json::Value::Bool(true)]);
+
+		let tensor = Tensor::from_string_array(([3usize], vec!["1", "{", "x"].into_boxed_slice()))?;
+		let err = tensor.try_extract_json().expect_err("\"{\" and \"x\" are not valid JSON");
+		assert_eq!(err.code(), crate::ErrorCode::InvalidArgument);
+		assert!(err.message().contains("element 1:"));
+		assert!(err.message().contains("element 2:"));
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_cosine_similarity() -> crate::Result<()> {
+		let a = Tensor::from_array(([2usize, 2, 2], vec![1.0_f32, 0.0, 3.0, 4.0, 0.0, 0.0, 1.0, 1.0]))?;
+		let b = Tensor::from_array(([2usize, 2, 2], vec![0_i32, 5, 3, 4, 1, 1, -2, -2]))?;
+		let similarity = a.cosine_similarity(&b, 2)?;
+		assert_eq!(similarity.shape(), &[2, 2]);
+		assert!(similarity[[0, 0]].abs() < 1e-6);
+		assert!((similarity[[0, 1]] - 1.0).abs() < 1e-6);
+		assert_eq!(similarity[[1, 0]], 0.0);
+		assert!((similarity[[1, 1]] + 1.0).abs() < 1e-6);
+
+		assert!(a.cosine_similarity(&b, 3).is_err());
+		let c = Tensor::from_array(([2usize, 4], vec![0.0_f32; 8]))?;
+		assert!(a.cosine_similarity(&c, 1).is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "half")]
+	fn test_bf16_rounding() -> crate::Result<()> {
+		use super::Rounding;
+
+		let data = [1.0_f32 + 3.0 / 256.0, f32::from_bits(0x7f80_0001), -0.0];
+		let nearest = Tensor::<half::bf16>::from_f32_slice([3], &data, Rounding::default())?;
+		let (_, nearest) = nearest.extract_raw_tensor();
+		assert_eq!(nearest[0], half::bf16::from_f32(1.015625));
+		assert!(nearest[1].is_nan());
+
+		let truncated = Tensor::<half::bf16>::from_f32_slice([3], &data, Rounding::Truncate)?;
+		let (_, truncated) = truncated.extract_raw_tensor();
+		assert_eq!(truncated[0], half::bf16::from_f32(1.0078125));
+		assert!(truncated[1].is_nan());
+		assert_eq!(truncated[2].to_bits(), half::bf16::NEG_ZERO.to_bits());
+
+		assert!(Tensor::<half::bf16>::from_f32_slice([2], &data, Rounding::Truncate).is_err());
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(all(feature = "half", feature = "ndarray"))]
+	fn test_try_extract_half_as_f32() -> crate::Result<()> {
+		let value = Tensor::from_array(([3usize], vec![half::bf16::from_f32(1.0), half::bf16::from_f32(-0.25), half::bf16::INFINITY]))?;
+		assert_eq!(value.try_extract_half_as_f32()?, ndarray::arr1(&[1.0, -0.25, f32::INFINITY]).into_dyn());
+
+		let value = Tensor::from_array(([1usize], vec![1.0_f32]))?;
+		assert!(value.try_extract_half_as_f32().is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_diff_summary() -> crate::Result<()> {
+		let a = Tensor::from_array(([2usize, 2], vec![1.0_f32, f32::NAN, 3.0, 4.0]))?;
+		let b = Tensor::from_array(([2usize, 2], vec![1.5_f64, f64::NAN, 3.0, 2.0]))?;
+		let diff = a.diff_summary(&b, 0.5)?;
+		assert_eq!(diff.max_abs_diff, 2.0);
+		assert_eq!(diff.mean_abs_diff, 0.625);
+		assert_eq!(diff.num_exceeding, 1);
+
+		let c = Tensor::from_array(([2usize, 2], vec![1_i32, 0, 3, 4]))?;
+		assert_eq!(a.diff_summary(&c, 0.0)?.max_abs_diff, f64::INFINITY);
+
+		let flat = Tensor::from_array(([4usize], vec![1.0_f32, 2.0, 3.0, 4.0]))?;
+		assert!(a.diff_summary(&flat, 0.0).is_err());
+		let bools = Tensor::from_array(([2usize, 2], vec![true, false, true, false]))?;
+		assert!(a.diff_summary(&bools, 0.0).is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_try_extract_2d_vec() -> crate::Result<()> {
+		let value = Tensor::from_array(([3usize, 2], vec![1_i64, 0, 2, 5, 0, 3]))?;
+		assert_eq!(value.try_extract_2d_vec::<i64>()?, vec![vec![1, 0], vec![2, 5], vec![0, 3]]);
+		assert!(value.try_extract_2d_vec::<i32>().is_err());
+
+		let value = Tensor::from_array(ndarray::Array2::<i64>::zeros((2, 0)))?;
+		assert_eq!(value.try_extract_2d_vec::<i64>()?, vec![Vec::<i64>::new(), Vec::new()]);
+
+		let value = Tensor::from_array(([6usize], vec![1_i64, 0, 2, 5, 0, 3]))?;
+		assert!(value.try_extract_2d_vec::<i64>().is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_quantize_from_f32() -> crate::Result<()> {
+		let tensor = DynTensor::quantize_from_f32([6], &[-1000.0, -2.5, -0.5, 0.5, 2.5, 3.5], TensorElementType::Int8, 1.0, 0)?;
+		assert_eq!(tensor.try_extract_raw_tensor::<i8>()?.1, &[-128, -2, 0, 0, 2, 4]);
+
+		assert!(DynTensor::quantize_from_f32([1], &[1.0], TensorElementType::Uint8, 0.0, 0).is_err());
+		assert!(DynTensor::quantize_from_f32([1], &[1.0], TensorElementType::Uint8, 1.0, -1).is_err());
+		assert!(DynTensor::quantize_from_f32([1], &[1.0], TensorElementType::Float32, 1.0, 0).is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn test_tensor_bytes_roundtrip() -> crate::Result<()> {
+		let tensor = Tensor::from_array(([2usize, 3], vec![1_i64, -2, 3, -4, 5, -6]))?;
+		let bytes = tensor.to_bytes()?;
+		let restored = DynTensor::from_bytes(&bytes, &Allocator::default())?;
+		assert_eq!(restored.try_extract_raw_tensor::<i64>()?, (vec![2, 3], &[1, -2, 3, -4, 5, -6][..]));
+
+		assert!(DynTensor::from_bytes(&bytes[..bytes.len() - 1], &Allocator::default()).is_err());
+		assert!(DynTensor::from_bytes(&[bytes.as_slice(), &[0]].concat(), &Allocator::default()).is_err());
+		assert!(DynTensor::from_bytes(b"not a tensor", &Allocator::default()).is_err());
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_tensor_index() -> crate::Result<()> {
 		let mut tensor = Tensor::new(&Allocator::default(), [1, 3, 224, 224])?;
@@ -395,4 +1192,11 @@ mod tests {
 
 		Ok(())
 	}
+
+	#[test]
+	fn test_element_type_try_from() {
+		assert_eq!(TensorElementType::try_from(ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_FLOAT).ok(), Some(TensorElementType::Float32));
+		let err = TensorElementType::try_from(ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_INT4).expect_err("int4 is unsupported");
+		assert_eq!(err.code(), crate::ErrorCode::UnsupportedTensorElementType);
+	}
 }
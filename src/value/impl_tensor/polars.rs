@@ -0,0 +1,187 @@
+use polars::prelude::{DataFrame, DataType, NamedFrom, Series};
+
+use super::{DynTensor, Tensor, TensorValueTypeMarker};
+use crate::{
+	error::{Error, ErrorCode, Result},
+	tensor::TensorElementType,
+	value::{Value, ValueType}
+};
+
+impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
+	/// Attempt to extract a 1-dimensional tensor into a [`polars::series::Series`] with the given name.
+	///
+	/// The series' data type is mapped from the tensor's element type, e.g. `Tensor<f32>` becomes a series of
+	/// `DataType::Float32`, and `Tensor<String>` becomes a series of `DataType::String`.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_array(([3usize], vec![0.1_f32, 0.7, 0.2].into_boxed_slice()))?;
+	///
+	/// let series = tensor.try_extract_series("probability")?;
+	/// assert_eq!(series.name().as_str(), "probability");
+	/// assert_eq!(series.len(), 3);
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is not 1-dimensional.
+	/// - The tensor's element type has no Polars equivalent (e.g. `f16`).
+	/// - The tensor's data is not allocated in CPU memory.
+	#[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+	pub fn try_extract_series(&self, name: &str) -> Result<Series> {
+		match self.try_dtype()? {
+			ValueType::Tensor { ty, dimensions } if dimensions.len() == 1 => self.strided_series(name, ty, 0, 1),
+			ValueType::Tensor { dimensions, .. } => Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Cannot extract a series from a tensor with {} dimensions; expected 1", dimensions.len())
+			)),
+			t => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract a series from {t}")))
+		}
+	}
+
+	/// Attempt to extract a 2-dimensional tensor of shape `[rows, columns]` into a [`polars::frame::DataFrame`], with
+	/// one column per entry in `column_names`.
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_array(([2usize, 2], vec![1_i64, 10, 2, 20].into_boxed_slice()))?;
+	///
+	/// let df = tensor.try_extract_dataframe(&["id", "score"])?;
+	/// assert_eq!(df.shape(), (2, 2));
+	/// assert_eq!(df.column("score").map(|c| c.len()).ok(), Some(2));
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor is not 2-dimensional, or the number of column names does not match the number of columns.
+	/// - The tensor's element type has no Polars equivalent (e.g. `f16`).
+	/// - The tensor's data is not allocated in CPU memory.
+	#[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+	pub fn try_extract_dataframe(&self, column_names: &[&str]) -> Result<DataFrame> {
+		let (ty, dimensions) = match self.try_dtype()? {
+			ValueType::Tensor { ty, dimensions } if dimensions.len() == 2 => (ty, dimensions),
+			ValueType::Tensor { dimensions, .. } => {
+				return Err(Error::new_with_code(
+					ErrorCode::InvalidArgument,
+					format!("Cannot extract a dataframe from a tensor with {} dimensions; expected 2", dimensions.len())
+				));
+			}
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot extract a dataframe from {t}")))
+		};
+
+		let num_columns = dimensions[1] as usize;
+		if column_names.len() != num_columns {
+			return Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Got {} column names for a tensor with {num_columns} columns", column_names.len())
+			));
+		}
+
+		let columns = column_names
+			.iter()
+			.enumerate()
+			.map(|(i, name)| self.strided_series(name, ty, i, num_columns))
+			.collect::<Result<Vec<_>>>()?;
+		DataFrame::new(columns).map_err(Error::wrap)
+	}
+
+	/// Creates a series from every `step`th element of the tensor's data, starting at `start`.
+	fn strided_series(&self, name: &str, ty: TensorElementType, start: usize, step: usize) -> Result<Series> {
+		macro_rules! series {
+			($t:ty) => {{
+				let (_, data) = self.try_extract_raw_tensor::<$t>()?;
+				Series::new(name.into(), data.iter().skip(start).step_by(step).copied().collect::<Vec<$t>>())
+			}};
+		}
+
+		Ok(match ty {
+			TensorElementType::Float32 => series!(f32),
+			TensorElementType::Float64 => series!(f64),
+			TensorElementType::Int8 => series!(i8),
+			TensorElementType::Int16 => series!(i16),
+			TensorElementType::Int32 => series!(i32),
+			TensorElementType::Int64 => series!(i64),
+			TensorElementType::Uint8 => series!(u8),
+			TensorElementType::Uint16 => series!(u16),
+			TensorElementType::Uint32 => series!(u32),
+			TensorElementType::Uint64 => series!(u64),
+			TensorElementType::Bool => series!(bool),
+			TensorElementType::String => {
+				let (_, data) = self.try_extract_raw_string_tensor()?;
+				Series::new(name.into(), data.into_iter().skip(start).step_by(step).collect::<Vec<String>>())
+			}
+			#[allow(unreachable_patterns)]
+			ty => {
+				return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Tensor<{ty}> cannot be converted to a Polars series")));
+			}
+		})
+	}
+}
+
+impl DynTensor {
+	/// Construct a 1-dimensional tensor from a [`polars::series::Series`], copying its data.
+	///
+	/// The tensor's element type is mapped from the series' data type, e.g. a series of `DataType::Float32` becomes a
+	/// `Tensor<f32>`, and a series of `DataType::String` becomes a `Tensor<String>`.
+	///
+	/// ```
+	/// # use ort::DynTensor;
+	/// # use polars::prelude::{NamedFrom, Series};
+	/// # fn main() -> ort::Result<()> {
+	/// let series = Series::new("feature".into(), &[1.0_f32, 2.0, 3.0]);
+	///
+	/// let tensor = DynTensor::from_series(&series)?;
+	/// assert_eq!(tensor.try_extract_raw_tensor::<f32>()?, (vec![3], &[1.0, 2.0, 3.0][..]));
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - The series contains null values. Nulls must be explicitly filled (e.g. with `Series::fill_null`) or dropped
+	///   before conversion.
+	/// - The series' data type has no tensor equivalent (e.g. categoricals, dates, or nested types).
+	#[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+	pub fn from_series(series: &Series) -> Result<DynTensor> {
+		if series.null_count() > 0 {
+			return Err(Error::new_with_code(
+				ErrorCode::InvalidArgument,
+				format!("Cannot create a tensor from a series containing {} null values; fill or drop them first", series.null_count())
+			));
+		}
+
+		macro_rules! tensor {
+			($chunked:ident) => {{
+				let data: Vec<_> = series.$chunked().map_err(Error::wrap)?.into_no_null_iter().collect();
+				Tensor::from_array(([data.len()], data)).map(|c| c.upcast())
+			}};
+		}
+
+		match series.dtype() {
+			DataType::Float32 => tensor!(f32),
+			DataType::Float64 => tensor!(f64),
+			DataType::Int8 => tensor!(i8),
+			DataType::Int16 => tensor!(i16),
+			DataType::Int32 => tensor!(i32),
+			DataType::Int64 => tensor!(i64),
+			DataType::UInt8 => tensor!(u8),
+			DataType::UInt16 => tensor!(u16),
+			DataType::UInt32 => tensor!(u32),
+			DataType::UInt64 => tensor!(u64),
+			DataType::Boolean => tensor!(bool),
+			DataType::String => {
+				let data: Vec<String> = series.str().map_err(Error::wrap)?.into_iter().flatten().map(str::to_owned).collect();
+				Tensor::from_string_array(([data.len()], data.into_boxed_slice())).map(|c| c.upcast())
+			}
+			dtype => Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot create a tensor from a series of type {dtype:?}")))
+		}
+	}
+}
@@ -0,0 +1,220 @@
+use std::{
+	ffi::CString,
+	marker::PhantomData,
+	ptr::{self, NonNull},
+	sync::{Arc, OnceLock}
+};
+
+use super::{DynTensor, TensorValueTypeMarker};
+use crate::{
+	error::{Error, ErrorCode, Result},
+	memory::Allocator,
+	ortsys,
+	tensor::{SUPPORTED_ELEMENT_TYPES, TensorElementType},
+	value::{Value, ValueInner, ValueType}
+};
+
+/// Magic bytes identifying a serialized tensor.
+const MAGIC: &[u8; 4] = b"ORTT";
+/// The current version of the serialization format. Bump this whenever the layout changes.
+const FORMAT_VERSION: u32 = 1;
+
+impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
+	/// Serializes this tensor into a self-describing, versioned byte blob, which can be converted back into a tensor
+	/// with [`DynTensor::from_bytes`].
+	///
+	/// The blob consists of (all integers little-endian):
+	/// - the 4 magic bytes `ORTT`;
+	/// - the format version, as a `u32`;
+	/// - the ONNX element type, as an `i32`;
+	/// - the rank, as a `u32`, followed by each dimension as an `i64`;
+	/// - for primitive tensors, the raw element data;
+	/// - for string tensors, the byte length of each string as a `u64`, followed by the concatenated UTF-8 data of all
+	///   strings.
+	///
+	/// ```
+	/// # use ort::{Allocator, DynTensor, Tensor};
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_string_array(([2], vec!["hello", "world"].into_boxed_slice()))?;
+	/// let bytes = tensor.to_bytes()?;
+	///
+	/// let restored = DynTensor::from_bytes(&bytes, &Allocator::default())?;
+	/// assert_eq!(restored.try_extract_raw_string_tensor()?, (vec![2], vec!["hello".to_string(), "world".to_string()]));
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor's data is not allocated in CPU memory.
+	pub fn to_bytes(&self) -> Result<Vec<u8>> {
+		let (ty, dimensions) = match self.try_dtype()? {
+			ValueType::Tensor { ty, dimensions } => (ty, dimensions),
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot serialize {t}; only tensors can be serialized")))
+		};
+
+		let mem = self.memory_info();
+		if !mem.is_cpu_accessible() {
+			return Err(Error::new(format!("Cannot serialize value on device `{}`, which is not CPU accessible", mem.allocation_device().as_str())));
+		}
+
+		let data_len = self.byte_size()?;
+		let mut bytes = Vec::with_capacity(16 + dimensions.len() * 8 + data_len);
+		bytes.extend_from_slice(MAGIC);
+		bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+		bytes.extend_from_slice(&(ort_sys::ONNXTensorElementDataType::from(ty) as i32).to_le_bytes());
+		bytes.extend_from_slice(&(dimensions.len() as u32).to_le_bytes());
+		for dim in &dimensions {
+			bytes.extend_from_slice(&dim.to_le_bytes());
+		}
+
+		if ty == TensorElementType::String {
+			let (_, strings) = self.try_extract_raw_string_tensor()?;
+			for string in &strings {
+				bytes.extend_from_slice(&(string.len() as u64).to_le_bytes());
+			}
+			for string in &strings {
+				bytes.extend_from_slice(string.as_bytes());
+			}
+		} else if data_len > 0 {
+			// ONNX Runtime only supports little-endian platforms, so the in-memory representation is already little-endian
+			let data = unsafe { std::slice::from_raw_parts(self.data_ptr()?.cast::<u8>(), data_len) };
+			bytes.extend_from_slice(data);
+		}
+
+		Ok(bytes)
+	}
+}
+
+impl DynTensor {
+	/// Deserializes a tensor from a byte blob created by [`Tensor::to_bytes`](crate::Tensor::to_bytes), allocating it
+	/// with the given allocator.
+	///
+	/// ```
+	/// # use ort::{Allocator, DynTensor, Tensor};
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_array(([2usize, 2], vec![1.0_f32, 2.0, 3.0, 4.0].into_boxed_slice()))?;
+	/// let bytes = tensor.to_bytes()?;
+	///
+	/// let restored = DynTensor::from_bytes(&bytes, &Allocator::default())?;
+	/// assert_eq!(restored.try_extract_raw_tensor::<f32>()?, (vec![2, 2], &[1.0, 2.0, 3.0, 4.0][..]));
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - The blob is malformed or truncated, or was produced by an incompatible version of the format.
+	/// - The blob contains an element type which is not supported by this build of `ort`.
+	/// - The allocator does not allocate CPU-accessible memory.
+	pub fn from_bytes(bytes: &[u8], allocator: &Allocator) -> Result<DynTensor> {
+		let mut reader = ByteReader(bytes);
+		if reader.take(MAGIC.len())? != MAGIC {
+			return Err(malformed("missing magic bytes"));
+		}
+		let version = u32::from_le_bytes(reader.take_array()?);
+		if version != FORMAT_VERSION {
+			return Err(malformed(format!("unsupported format version {version}, expected {FORMAT_VERSION}")));
+		}
+
+		let type_code = i32::from_le_bytes(reader.take_array()?);
+		let ty = SUPPORTED_ELEMENT_TYPES
+			.iter()
+			.copied()
+			.find(|ty| ort_sys::ONNXTensorElementDataType::from(*ty) as i32 == type_code)
+			.ok_or_else(|| malformed(format!("unsupported element type {type_code}")))?;
+
+		let rank = u32::from_le_bytes(reader.take_array()?) as usize;
+		let mut shape = Vec::with_capacity(rank.min(reader.0.len() / 8));
+		let mut len = 1usize;
+		for _ in 0..rank {
+			let dim = i64::from_le_bytes(reader.take_array()?);
+			len = usize::try_from(dim)
+				.ok()
+				.and_then(|dim| len.checked_mul(dim))
+				.ok_or_else(|| malformed(format!("invalid dimension {dim}")))?;
+			shape.push(dim);
+		}
+
+		// parse & validate the data before allocating the tensor, so a malformed blob can't trigger a huge allocation
+		let (strings, data) = if ty == TensorElementType::String {
+			if len > reader.0.len() / 8 {
+				return Err(malformed("unexpected end of data"));
+			}
+			let lengths = (0..len)
+				.map(|_| Ok(u64::from_le_bytes(reader.take_array()?) as usize))
+				.collect::<Result<Vec<usize>>>()?;
+			let strings = lengths
+				.into_iter()
+				.map(|string_len| {
+					let string = std::str::from_utf8(reader.take(string_len)?).map_err(|_| malformed("invalid UTF-8 in string data"))?;
+					CString::new(string).map_err(|_| malformed("string data contains a null byte"))
+				})
+				.collect::<Result<Vec<CString>>>()?;
+			(strings, &[][..])
+		} else {
+			let width = ty.try_byte_size().expect("non-string tensor element types have a fixed width");
+			let data = reader.take(len.checked_mul(width).ok_or_else(|| malformed("tensor is too large"))?)?;
+			if ty == TensorElementType::Bool && data.iter().any(|&b| b > 1) {
+				return Err(malformed("invalid boolean data"));
+			}
+			(Vec::new(), data)
+		};
+		if !reader.0.is_empty() {
+			return Err(malformed(format!("{} trailing bytes", reader.0.len())));
+		}
+
+		if ty != TensorElementType::String && !allocator.memory_info().is_cpu_accessible() {
+			return Err(Error::new(format!(
+				"Cannot deserialize into an allocator on device `{}`, which is not CPU accessible",
+				allocator.memory_info().allocation_device().as_str()
+			)));
+		}
+
+		let mut value_ptr: *mut ort_sys::OrtValue = ptr::null_mut();
+		ortsys![
+			unsafe CreateTensorAsOrtValue(allocator.ptr.as_ptr(), shape.as_ptr(), shape.len() as _, ty.into(), &mut value_ptr)?;
+			nonNull(value_ptr)
+		];
+		let mut value: DynTensor = Value {
+			inner: Arc::new(ValueInner::RustOwned {
+				ptr: unsafe { NonNull::new_unchecked(value_ptr) },
+				_array: Box::new(()),
+				_memory_info: None,
+				dims: OnceLock::new()
+			}),
+			_markers: PhantomData
+		};
+
+		if ty == TensorElementType::String {
+			let string_pointers = strings.iter().map(|s| s.as_ptr()).collect::<Vec<_>>();
+			ortsys![unsafe FillStringTensor(value.ptr(), string_pointers.as_ptr(), string_pointers.len() as _)?];
+		} else if !data.is_empty() {
+			unsafe { ptr::copy_nonoverlapping(data.as_ptr(), value.data_ptr_mut()?.cast::<u8>(), data.len()) };
+		}
+
+		Ok(value)
+	}
+}
+
+fn malformed(reason: impl AsRef<str>) -> Error {
+	Error::new_with_code(ErrorCode::InvalidArgument, format!("Malformed serialized tensor: {}", reason.as_ref()))
+}
+
+struct ByteReader<'b>(&'b [u8]);
+
+impl<'b> ByteReader<'b> {
+	fn take(&mut self, n: usize) -> Result<&'b [u8]> {
+		if self.0.len() < n {
+			return Err(malformed("unexpected end of data"));
+		}
+		let (head, tail) = self.0.split_at(n);
+		self.0 = tail;
+		Ok(head)
+	}
+
+	fn take_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+		Ok(self.take(N)?.try_into().expect("slice has exactly N elements"))
+	}
+}
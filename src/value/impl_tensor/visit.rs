@@ -0,0 +1,147 @@
+use super::TensorValueTypeMarker;
+use crate::{
+	error::{Error, ErrorCode, Result},
+	tensor::TensorElementType,
+	value::{Value, ValueType}
+};
+
+macro_rules! visitor_methods {
+	($($(#[$meta:meta])* $method:ident($t:ty) => $ty:ident),+) => {
+		$(
+			#[doc = concat!("Visits the data of a `Tensor<", stringify!($t), ">`.")]
+			///
+			/// The default implementation returns an error.
+			$(#[$meta])*
+			fn $method(&mut self, shape: &[i64], data: &[$t]) -> Result<()> {
+				let _ = (shape, data);
+				Err(unsupported(TensorElementType::$ty))
+			}
+		)+
+	};
+}
+
+/// A visitor over the data of a tensor of any element type, used with [`Tensor::visit`](crate::Tensor::visit).
+///
+/// Each method receives the tensor's shape and a view of its data, and is only called for tensors of the
+/// corresponding element type. All methods return an error by default, so implementors only need to implement the
+/// methods for the types they support.
+///
+/// ```
+/// # use ort::{Result, Tensor, TensorVisitor};
+/// struct Sum(f64);
+///
+/// impl TensorVisitor for Sum {
+/// 	fn visit_f32_slice(&mut self, _: &[i64], data: &[f32]) -> Result<()> {
+/// 		self.0 = data.iter().map(|&x| x as f64).sum();
+/// 		Ok(())
+/// 	}
+///
+/// 	fn visit_i64_slice(&mut self, _: &[i64], data: &[i64]) -> Result<()> {
+/// 		self.0 = data.iter().map(|&x| x as f64).sum();
+/// 		Ok(())
+/// 	}
+/// }
+///
+/// # fn main() -> ort::Result<()> {
+/// let mut sum = Sum(0.0);
+/// Tensor::from_array(([3usize], vec![1_i64, 2, 3].into_boxed_slice()))?.visit(&mut sum)?;
+/// assert_eq!(sum.0, 6.0);
+///
+/// // `Sum` does not support strings
+/// assert!(Tensor::from_string_array(([1], vec!["a"].into_boxed_slice()))?.visit(&mut sum).is_err());
+/// # 	Ok(())
+/// # }
+/// ```
+pub trait TensorVisitor {
+	visitor_methods!(
+		visit_f32_slice(f32) => Float32,
+		visit_f64_slice(f64) => Float64,
+		#[cfg(feature = "half")]
+		#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+		visit_f16_slice(half::f16) => Float16,
+		#[cfg(feature = "half")]
+		#[cfg_attr(docsrs, doc(cfg(feature = "half")))]
+		visit_bf16_slice(half::bf16) => Bfloat16,
+		visit_i8_slice(i8) => Int8,
+		visit_i16_slice(i16) => Int16,
+		visit_i32_slice(i32) => Int32,
+		visit_i64_slice(i64) => Int64,
+		visit_u8_slice(u8) => Uint8,
+		visit_u16_slice(u16) => Uint16,
+		visit_u32_slice(u32) => Uint32,
+		visit_u64_slice(u64) => Uint64,
+		#[cfg(feature = "complex")]
+		#[cfg_attr(docsrs, doc(cfg(feature = "complex")))]
+		visit_complex64_slice(num_complex::Complex<f32>) => Complex64,
+		#[cfg(feature = "complex")]
+		#[cfg_attr(docsrs, doc(cfg(feature = "complex")))]
+		visit_complex128_slice(num_complex::Complex<f64>) => Complex128,
+		#[cfg(feature = "fp8")]
+		#[cfg_attr(docsrs, doc(cfg(feature = "fp8")))]
+		visit_f8e4m3_slice(float8::F8E4M3) => Float8E4M3FN,
+		#[cfg(feature = "fp8")]
+		#[cfg_attr(docsrs, doc(cfg(feature = "fp8")))]
+		visit_f8e5m2_slice(float8::F8E5M2) => Float8E5M2,
+		visit_bool_slice(bool) => Bool,
+		visit_strings(String) => String
+	);
+}
+
+fn unsupported(ty: TensorElementType) -> Error {
+	Error::new_with_code(ErrorCode::InvalidArgument, format!("Visitor does not support Tensor<{ty}>"))
+}
+
+impl<Type: TensorValueTypeMarker + ?Sized> Value<Type> {
+	/// Visits this tensor's data with `visitor`, calling the visitor method corresponding to the tensor's element type.
+	///
+	/// See [`TensorVisitor`] for an example.
+	///
+	/// # Errors
+	/// May return an error if:
+	/// - This is a [`crate::DynValue`], and the value is not actually a tensor.
+	/// - The tensor's data is not allocated in CPU memory.
+	/// - The visitor does not support the tensor's element type, or returns an error itself.
+	pub fn visit(&self, visitor: &mut dyn TensorVisitor) -> Result<()> {
+		let ty = match self.try_dtype()? {
+			ValueType::Tensor { ty, .. } => ty,
+			t => return Err(Error::new_with_code(ErrorCode::InvalidArgument, format!("Cannot visit {t}; expected a tensor")))
+		};
+
+		macro_rules! visit {
+			($method:ident($t:ty)) => {{
+				let (shape, data) = self.try_extract_raw_tensor::<$t>()?;
+				visitor.$method(&shape, data)
+			}};
+		}
+
+		match ty {
+			TensorElementType::Float32 => visit!(visit_f32_slice(f32)),
+			TensorElementType::Float64 => visit!(visit_f64_slice(f64)),
+			#[cfg(feature = "half")]
+			TensorElementType::Float16 => visit!(visit_f16_slice(half::f16)),
+			#[cfg(feature = "half")]
+			TensorElementType::Bfloat16 => visit!(visit_bf16_slice(half::bf16)),
+			TensorElementType::Int8 => visit!(visit_i8_slice(i8)),
+			TensorElementType::Int16 => visit!(visit_i16_slice(i16)),
+			TensorElementType::Int32 => visit!(visit_i32_slice(i32)),
+			TensorElementType::Int64 => visit!(visit_i64_slice(i64)),
+			TensorElementType::Uint8 => visit!(visit_u8_slice(u8)),
+			TensorElementType::Uint16 => visit!(visit_u16_slice(u16)),
+			TensorElementType::Uint32 => visit!(visit_u32_slice(u32)),
+			TensorElementType::Uint64 => visit!(visit_u64_slice(u64)),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex64 => visit!(visit_complex64_slice(num_complex::Complex<f32>)),
+			#[cfg(feature = "complex")]
+			TensorElementType::Complex128 => visit!(visit_complex128_slice(num_complex::Complex<f64>)),
+			TensorElementType::Bool => visit!(visit_bool_slice(bool)),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E4M3FN => visit!(visit_f8e4m3_slice(float8::F8E4M3)),
+			#[cfg(feature = "fp8")]
+			TensorElementType::Float8E5M2 => visit!(visit_f8e5m2_slice(float8::F8E5M2)),
+			TensorElementType::String => {
+				let (shape, data) = self.try_extract_raw_string_tensor()?;
+				visitor.visit_strings(&shape, &data)
+			}
+		}
+	}
+}
@@ -5,19 +5,24 @@ use std::{
 	mem::ManuallyDrop,
 	ops::{Deref, DerefMut},
 	ptr::NonNull,
-	sync::Arc
+	sync::{Arc, OnceLock}
 };
 
 mod impl_map;
 mod impl_sequence;
 mod impl_tensor;
 
+#[cfg(feature = "dlpack")]
+pub use self::impl_tensor::dlpack;
 pub use self::{
 	impl_map::{DynMap, DynMapRef, DynMapRefMut, DynMapValueType, Map, MapRef, MapRefMut, MapValueType, MapValueTypeMarker},
 	impl_sequence::{
 		DynSequence, DynSequenceRef, DynSequenceRefMut, DynSequenceValueType, Sequence, SequenceRef, SequenceRefMut, SequenceValueType, SequenceValueTypeMarker
 	},
-	impl_tensor::{DynTensor, DynTensorRef, DynTensorRefMut, DynTensorValueType, Tensor, TensorRef, TensorRefMut, TensorValueType, TensorValueTypeMarker}
+	impl_tensor::{
+		DiffSummary, DynTensor, DynTensorRef, DynTensorRefMut, DynTensorValueType, ExtractInto, FromValue, Rounding, Tensor, TensorData, TensorDescriptor,
+		TensorRef, TensorRefMut, TensorTypeInfo, TensorValueType, TensorValueTypeMarker, TensorVisitor, ToValue, zip_string_rows, zip_string_scores
+	}
 };
 use crate::{
 	error::{Error, ErrorCode, Result},
@@ -88,7 +93,7 @@ pub enum ValueType {
 }
 
 impl ValueType {
-	pub(crate) fn from_type_info(typeinfo_ptr: *mut ort_sys::OrtTypeInfo) -> Self {
+	pub(crate) fn from_type_info(typeinfo_ptr: *mut ort_sys::OrtTypeInfo) -> Result<Self> {
 		let mut ty: ort_sys::ONNXType = ort_sys::ONNXType::ONNX_TYPE_UNKNOWN;
 		ortsys![unsafe GetOnnxTypeFromTypeInfo(typeinfo_ptr, &mut ty)]; // infallible
 		let io_type = match ty {
@@ -114,7 +119,7 @@ impl ValueType {
 				let mut contained_type: *mut ort_sys::OrtTypeInfo = std::ptr::null_mut();
 				ortsys![unsafe GetOptionalContainedTypeInfo(info_ptr, &mut contained_type)]; // infallible
 
-				ValueType::Optional(Box::new(ValueType::from_type_info(contained_type)))
+				ValueType::from_type_info(contained_type).map(|ty| ValueType::Optional(Box::new(ty)))
 			}
 			_ => unreachable!()
 		};
@@ -206,12 +211,17 @@ pub(crate) enum ValueInner {
 		ptr: NonNull<ort_sys::OrtValue>,
 		_array: Box<dyn Any>,
 		/// Hold onto the `MemoryInfo` that we create in `Value::from_array`.
-		_memory_info: Option<MemoryInfo>
+		_memory_info: Option<MemoryInfo>,
+		/// The tensor's shape, cached for [`Tensor::descriptor`].
+		dims: OnceLock<Box<[i64]>>
 	},
 	CppOwned {
 		ptr: NonNull<ort_sys::OrtValue>,
 		/// Whether to release the value pointer on drop.
 		drop: bool,
+		/// Whether the value's data is borrowed from memory the value does not keep alive, i.e. when it is created
+		/// with [`TensorRefMut::from_raw`] or [`Tensor::from_array_view`].
+		borrowed: bool,
 		/// Hold [`SharedSessionInner`] to ensure that the value can stay alive after the main session is dropped.
 		///
 		/// This may be `None` if the value is created outside of a session or if the value does not need to hold onto
@@ -219,7 +229,9 @@ pub(crate) enum ValueInner {
 		/// - a map value can be created independently of a session, and thus we wouldn't have anything to hold on to;
 		/// - this is only ever used by `ValueRef`s, whos owner value (which *is* holding the session Arc) will outlive
 		///   it.
-		_session: Option<Arc<SharedSessionInner>>
+		_session: Option<Arc<SharedSessionInner>>,
+		/// The tensor's shape, cached for [`Tensor::descriptor`].
+		dims: OnceLock<Box<[i64]>>
 	}
 }
 
@@ -229,6 +241,12 @@ impl ValueInner {
 			ValueInner::CppOwned { ptr, .. } | ValueInner::RustOwned { ptr, .. } => ptr.as_ptr()
 		}
 	}
+
+	pub(crate) fn dims(&self) -> &OnceLock<Box<[i64]>> {
+		match self {
+			ValueInner::CppOwned { dims, .. } | ValueInner::RustOwned { dims, .. } => dims
+		}
+	}
 }
 
 /// A temporary version of a [`Value`] with a lifetime specifier.
@@ -250,7 +268,7 @@ impl<'v, Type: ValueTypeMarker + ?Sized> ValueRef<'v, Type> {
 	/// variant, like [`TensorRef<T>`].
 	#[inline]
 	pub fn downcast<OtherType: ValueTypeMarker + DowncastableTarget + ?Sized>(self) -> Result<ValueRef<'v, OtherType>> {
-		let dt = self.dtype();
+		let dt = self.try_dtype()?;
 		if OtherType::can_downcast(&dt) {
 			Ok(unsafe { std::mem::transmute::<ValueRef<'v, Type>, ValueRef<'v, OtherType>>(self) })
 		} else {
@@ -259,17 +277,24 @@ impl<'v, Type: ValueTypeMarker + ?Sized> ValueRef<'v, Type> {
 	}
 
 	/// Attempts to upgrade this `ValueRef` to an owned [`Value`] holding the same data.
+	///
+	/// Returns `Err(self)` if the value does not own its data (see [`Value::owns_data`]), like views created with
+	/// [`Tensor::from_array_view`] or values passed to operator kernels, since the upgraded value could otherwise
+	/// outlive the memory it references.
 	pub fn try_upgrade(self) -> Result<Value<Type>, Self> {
-		// We cannot upgade a value which we cannot drop, i.e. `ValueRef`s used in operator kernels. Those only last for the
-		// duration of the kernel, allowing an upgrade would allow a UAF.
+		// We cannot upgade a value which we cannot drop, i.e. `ValueRef`s used in operator kernels, or a value which borrows
+		// its data, i.e. views created with `Tensor::from_array_view`. Those only last for the duration of the kernel or
+		// the borrow, so allowing an upgrade would allow a UAF.
 		if match &*self.inner.inner {
-			ValueInner::CppOwned { drop, .. } => !drop,
+			ValueInner::CppOwned { drop, borrowed, .. } => !drop || *borrowed,
 			_ => false
 		} {
 			return Err(self);
 		}
 
-		Ok(ManuallyDrop::into_inner(self.inner))
+		let mut this = ManuallyDrop::new(self);
+		// SAFETY: `this` is never dropped, so `inner` is only taken once
+		Ok(unsafe { ManuallyDrop::take(&mut this.inner) })
 	}
 
 	pub fn into_dyn(self) -> ValueRef<'v, DynValueTypeMarker> {
@@ -285,6 +310,14 @@ impl<'v, Type: ValueTypeMarker + ?Sized> Deref for ValueRef<'v, Type> {
 	}
 }
 
+impl<'v, Type: ValueTypeMarker + ?Sized> Drop for ValueRef<'v, Type> {
+	fn drop(&mut self) {
+		// references to values which shouldn't be released (i.e. those in operator kernels) are marked as such in
+		// `ValueInner`, so it's always safe to drop our inner value
+		unsafe { ManuallyDrop::drop(&mut self.inner) };
+	}
+}
+
 /// A mutable temporary version of a [`Value`] with a lifetime specifier.
 #[derive(Debug)]
 pub struct ValueRefMut<'v, Type: ValueTypeMarker + ?Sized = DynValueTypeMarker> {
@@ -304,7 +337,7 @@ impl<'v, Type: ValueTypeMarker + ?Sized> ValueRefMut<'v, Type> {
 	/// strongly typed variant, like [`TensorRefMut<T>`].
 	#[inline]
 	pub fn downcast<OtherType: ValueTypeMarker + DowncastableTarget + ?Sized>(self) -> Result<ValueRefMut<'v, OtherType>> {
-		let dt = self.dtype();
+		let dt = self.try_dtype()?;
 		if OtherType::can_downcast(&dt) {
 			Ok(unsafe { std::mem::transmute::<ValueRefMut<'v, Type>, ValueRefMut<'v, OtherType>>(self) })
 		} else {
@@ -313,17 +346,24 @@ impl<'v, Type: ValueTypeMarker + ?Sized> ValueRefMut<'v, Type> {
 	}
 
 	/// Attempts to upgrade this `ValueRefMut` to an owned [`Value`] holding the same data.
+	///
+	/// Returns `Err(self)` if the value does not own its data (see [`Value::owns_data`]), like views created with
+	/// [`TensorRefMut::from_raw`] or values passed to operator kernels, since the upgraded value could otherwise
+	/// outlive the memory it references.
 	pub fn try_upgrade(self) -> Result<Value<Type>, Self> {
-		// We cannot upgade a value which we cannot drop, i.e. `ValueRef`s used in operator kernels. Those only last for the
-		// duration of the kernel, allowing an upgrade would allow a UAF.
+		// We cannot upgade a value which we cannot drop, i.e. `ValueRef`s used in operator kernels, or a value which borrows
+		// its data, i.e. views created with `TensorRefMut::from_raw`. Those only last for the duration of the kernel or
+		// the borrow, so allowing an upgrade would allow a UAF.
 		if match &*self.inner.inner {
-			ValueInner::CppOwned { drop, .. } => !drop,
+			ValueInner::CppOwned { drop, borrowed, .. } => !drop || *borrowed,
 			_ => false
 		} {
 			return Err(self);
 		}
 
-		Ok(ManuallyDrop::into_inner(self.inner))
+		let mut this = ManuallyDrop::new(self);
+		// SAFETY: `this` is never dropped, so `inner` is only taken once
+		Ok(unsafe { ManuallyDrop::take(&mut this.inner) })
 	}
 
 	pub fn into_dyn(self) -> ValueRefMut<'v, DynValueTypeMarker> {
@@ -345,6 +385,12 @@ impl<'v, Type: ValueTypeMarker + ?Sized> DerefMut for ValueRefMut<'v, Type> {
 	}
 }
 
+impl<'v, Type: ValueTypeMarker + ?Sized> Drop for ValueRefMut<'v, Type> {
+	fn drop(&mut self) {
+		unsafe { ManuallyDrop::drop(&mut self.inner) };
+	}
+}
+
 /// A [`Value`] contains data for inputs/outputs in ONNX Runtime graphs. [`Value`]s can be a [`Tensor`], [`Sequence`]
 /// (aka array/vector), or [`Map`].
 ///
@@ -446,13 +492,20 @@ unsafe impl<Type: ValueTypeMarker + ?Sized> Sync for Value<Type> {}
 
 impl<Type: ValueTypeMarker + ?Sized> Value<Type> {
 	/// Returns the data type of this [`Value`].
+	///
+	/// # Panics
+	/// Panics if this value is (or contains) a tensor whose element type is not supported by this build of `ort`; use
+	/// [`Value::try_dtype`] to handle such values gracefully.
 	pub fn dtype(&self) -> ValueType {
+		self.try_dtype().unwrap_or_else(|e| panic!("{e}"))
+	}
+
+	/// Returns the data type of this [`Value`], or an error with [`ErrorCode::UnsupportedTensorElementType`] if this
+	/// value is (or contains) a tensor whose element type is not supported by this build of `ort`.
+	pub fn try_dtype(&self) -> Result<ValueType> {
 		let mut typeinfo_ptr: *mut ort_sys::OrtTypeInfo = std::ptr::null_mut();
-		ortsys![unsafe GetTypeInfo(self.ptr(), &mut typeinfo_ptr)]; // infallible
 		// `typeinfo_ptr` may be null in exceptionally rare cases
-		if typeinfo_ptr.is_null() {
-			panic!("unexpected UNKNOWN value type info");
-		}
+		ortsys![unsafe GetTypeInfo(self.ptr(), &mut typeinfo_ptr)?; nonNull(typeinfo_ptr)];
 		ValueType::from_type_info(typeinfo_ptr)
 	}
 
@@ -469,7 +522,13 @@ impl<Type: ValueTypeMarker + ?Sized> Value<Type> {
 	#[must_use]
 	pub unsafe fn from_ptr(ptr: NonNull<ort_sys::OrtValue>, session: Option<Arc<SharedSessionInner>>) -> Value<Type> {
 		Value {
-			inner: Arc::new(ValueInner::CppOwned { ptr, drop: true, _session: session }),
+			inner: Arc::new(ValueInner::CppOwned {
+				ptr,
+				drop: true,
+				borrowed: false,
+				_session: session,
+				dims: OnceLock::new()
+			}),
 			_markers: PhantomData
 		}
 	}
@@ -479,7 +538,13 @@ impl<Type: ValueTypeMarker + ?Sized> Value<Type> {
 	#[must_use]
 	pub(crate) unsafe fn from_ptr_nodrop(ptr: NonNull<ort_sys::OrtValue>, session: Option<Arc<SharedSessionInner>>) -> Value<Type> {
 		Value {
-			inner: Arc::new(ValueInner::CppOwned { ptr, drop: false, _session: session }),
+			inner: Arc::new(ValueInner::CppOwned {
+				ptr,
+				drop: false,
+				borrowed: true,
+				_session: session,
+				dims: OnceLock::new()
+			}),
 			_markers: PhantomData
 		}
 	}
@@ -489,6 +554,32 @@ impl<Type: ValueTypeMarker + ?Sized> Value<Type> {
 		self.inner.ptr()
 	}
 
+	/// Returns `true` if this value owns its data, or keeps it alive for at least as long as the value itself lives,
+	/// or `false` if the data is borrowed from external memory (like [`Tensor::from_array_view`] or
+	/// [`TensorRefMut::from_raw`]).
+	///
+	/// Values which do not own their data must not outlive the memory they reference, so they cannot be upgraded to
+	/// an owned value via [`ValueRef::try_upgrade`].
+	///
+	/// ```
+	/// # use ort::Tensor;
+	/// # fn main() -> ort::Result<()> {
+	/// let tensor = Tensor::from_array(([3usize], vec![1.0_f32, 2.0, 3.0]))?;
+	/// assert!(tensor.owns_data());
+	///
+	/// #[cfg(feature = "ndarray")]
+	/// {
+	/// 	let array = ndarray::Array1::from_vec(vec![1.0_f32, 2.0, 3.0]);
+	/// 	let view = Tensor::from_array_view(array.view())?;
+	/// 	assert!(!view.owns_data());
+	/// }
+	/// # 	Ok(())
+	/// # }
+	/// ```
+	pub fn owns_data(&self) -> bool {
+		!matches!(&*self.inner, ValueInner::CppOwned { borrowed: true, .. })
+	}
+
 	/// Create a view of this value's data.
 	pub fn view(&self) -> ValueRef<'_, Type> {
 		ValueRef::new(Value {
@@ -533,7 +624,7 @@ impl Value<DynValueTypeMarker> {
 	/// like [`Tensor<T>`].
 	#[inline]
 	pub fn downcast<OtherType: ValueTypeMarker + DowncastableTarget + ?Sized>(self) -> Result<Value<OtherType>> {
-		let dt = self.dtype();
+		let dt = self.try_dtype()?;
 		if OtherType::can_downcast(&dt) {
 			Ok(unsafe { std::mem::transmute::<Value<DynValueTypeMarker>, Value<OtherType>>(self) })
 		} else {
@@ -545,7 +636,7 @@ impl Value<DynValueTypeMarker> {
 	/// variant, like [`TensorRef<T>`].
 	#[inline]
 	pub fn downcast_ref<OtherType: ValueTypeMarker + DowncastableTarget + ?Sized>(&self) -> Result<ValueRef<'_, OtherType>> {
-		let dt = self.dtype();
+		let dt = self.try_dtype()?;
 		if OtherType::can_downcast(&dt) {
 			Ok(ValueRef::new(Value {
 				inner: Arc::clone(&self.inner),
@@ -560,7 +651,7 @@ impl Value<DynValueTypeMarker> {
 	/// mutable-reference variant, like [`TensorRefMut<T>`].
 	#[inline]
 	pub fn downcast_mut<OtherType: ValueTypeMarker + DowncastableTarget + ?Sized>(&mut self) -> Result<ValueRefMut<'_, OtherType>> {
-		let dt = self.dtype();
+		let dt = self.try_dtype()?;
 		if OtherType::can_downcast(&dt) {
 			Ok(ValueRefMut::new(Value {
 				inner: Arc::clone(&self.inner),
@@ -585,7 +676,7 @@ impl Drop for ValueInner {
 	}
 }
 
-pub(crate) unsafe fn extract_data_type_from_tensor_info(info_ptr: *const ort_sys::OrtTensorTypeAndShapeInfo) -> ValueType {
+pub(crate) unsafe fn extract_data_type_from_tensor_info(info_ptr: *const ort_sys::OrtTensorTypeAndShapeInfo) -> Result<ValueType> {
 	let mut type_sys = ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
 	ortsys![GetTensorElementType(info_ptr, &mut type_sys)];
 	assert_ne!(type_sys, ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED);
@@ -596,13 +687,13 @@ pub(crate) unsafe fn extract_data_type_from_tensor_info(info_ptr: *const ort_sys
 	let mut node_dims: Vec<i64> = vec![0; num_dims as _];
 	ortsys![GetDimensions(info_ptr, node_dims.as_mut_ptr(), num_dims as _)];
 
-	ValueType::Tensor {
-		ty: type_sys.into(),
+	Ok(ValueType::Tensor {
+		ty: type_sys.try_into()?,
 		dimensions: node_dims
-	}
+	})
 }
 
-pub(crate) unsafe fn extract_data_type_from_sequence_info(info_ptr: *const ort_sys::OrtSequenceTypeInfo) -> ValueType {
+pub(crate) unsafe fn extract_data_type_from_sequence_info(info_ptr: *const ort_sys::OrtSequenceTypeInfo) -> Result<ValueType> {
 	let mut element_type_info: *mut ort_sys::OrtTypeInfo = std::ptr::null_mut();
 	ortsys![GetSequenceElementType(info_ptr, &mut element_type_info)]; // infallible
 
@@ -613,20 +704,20 @@ pub(crate) unsafe fn extract_data_type_from_sequence_info(info_ptr: *const ort_s
 		ort_sys::ONNXType::ONNX_TYPE_TENSOR => {
 			let mut info_ptr: *const ort_sys::OrtTensorTypeAndShapeInfo = std::ptr::null_mut();
 			ortsys![CastTypeInfoToTensorInfo(element_type_info, &mut info_ptr)]; // infallible
-			let ty = extract_data_type_from_tensor_info(info_ptr);
-			ValueType::Sequence(Box::new(ty))
+			let ty = extract_data_type_from_tensor_info(info_ptr)?;
+			Ok(ValueType::Sequence(Box::new(ty)))
 		}
 		ort_sys::ONNXType::ONNX_TYPE_MAP => {
 			let mut info_ptr: *const ort_sys::OrtMapTypeInfo = std::ptr::null_mut();
 			ortsys![CastTypeInfoToMapTypeInfo(element_type_info, &mut info_ptr)]; // infallible
-			let ty = extract_data_type_from_map_info(info_ptr);
-			ValueType::Sequence(Box::new(ty))
+			let ty = extract_data_type_from_map_info(info_ptr)?;
+			Ok(ValueType::Sequence(Box::new(ty)))
 		}
 		_ => unreachable!()
 	}
 }
 
-pub(crate) unsafe fn extract_data_type_from_map_info(info_ptr: *const ort_sys::OrtMapTypeInfo) -> ValueType {
+pub(crate) unsafe fn extract_data_type_from_map_info(info_ptr: *const ort_sys::OrtMapTypeInfo) -> Result<ValueType> {
 	let mut key_type_sys = ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED;
 	ortsys![GetMapKeyType(info_ptr, &mut key_type_sys)]; // infallible
 	assert_ne!(key_type_sys, ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED);
@@ -639,14 +730,16 @@ pub(crate) unsafe fn extract_data_type_from_map_info(info_ptr: *const ort_sys::O
 	ortsys![GetTensorElementType(value_info_ptr, &mut value_type_sys)]; // infallible
 	assert_ne!(value_type_sys, ort_sys::ONNXTensorElementDataType::ONNX_TENSOR_ELEMENT_DATA_TYPE_UNDEFINED);
 
-	ValueType::Map {
-		key: key_type_sys.into(),
-		value: value_type_sys.into()
-	}
+	Ok(ValueType::Map {
+		key: key_type_sys.try_into()?,
+		value: value_type_sys.try_into()?
+	})
 }
 
 #[cfg(test)]
 mod tests {
+	use std::sync::Arc;
+
 	use super::{DynTensorValueType, Map, Sequence, Tensor, TensorRef, TensorValueType};
 	use crate::{Allocator, TensorRefMut};
 
@@ -698,6 +791,43 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn test_view_drop() -> crate::Result<()> {
+		let mut tensor: Tensor<i32> = Tensor::from_array((vec![3], vec![1, 2, 3]))?;
+
+		// views share the value's inner data, and must release their reference to it when dropped
+		{
+			let _view = tensor.view();
+			assert_eq!(Arc::strong_count(&tensor.inner), 2);
+		}
+		assert_eq!(Arc::strong_count(&tensor.inner), 1);
+		{
+			let view = tensor.view_mut();
+			assert_eq!(Arc::strong_count(&view.inner.inner), 2);
+		}
+		assert_eq!(Arc::strong_count(&tensor.inner), 1);
+
+		// ...unless they are upgraded, in which case the upgraded value holds the reference
+		let upgraded = tensor.view().try_upgrade().expect("tensor views should be upgradable");
+		assert_eq!(Arc::strong_count(&tensor.inner), 2);
+		drop(upgraded);
+		assert_eq!(Arc::strong_count(&tensor.inner), 1);
+
+		Ok(())
+	}
+
+	#[test]
+	#[cfg(feature = "ndarray")]
+	fn test_upgrade_borrowed() -> crate::Result<()> {
+		let array = ndarray::Array1::from_vec(vec![1.0_f32, 2.0, 3.0]);
+		let view = Tensor::from_array_view(array.view())?;
+		let view = view.try_upgrade().expect_err("borrowed views should not be upgradable");
+		// the view is handed back intact
+		assert_eq!(view.extract_raw_tensor(), (vec![3], &[1.0, 2.0, 3.0][..]));
+
+		Ok(())
+	}
+
 	#[test]
 	fn test_sequence_map() -> crate::Result<()> {
 		let map_contents = [("meaning".to_owned(), 42.0), ("pi".to_owned(), std::f32::consts::PI)];